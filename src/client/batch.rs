@@ -0,0 +1,250 @@
+use std::fmt::Debug;
+
+use futures::{stream, StreamExt};
+
+use crate::{response::Response, Error, Flat, IntoRequest, ResponseError, ServiceRequest};
+
+use super::{Client, ServiceClient};
+
+/// Independent requests sent through `ServiceClient::send` with at most
+/// `concurrency` of them in flight, so the rate limiter of the client, and
+/// the `RetryPolicy` given to `ClientBuilder::retry_policy` if any, apply to
+/// each one. Nothing is spawned: dropping the future cancels every request
+/// still in flight.
+pub struct Batch<IR> {
+    client: Client,
+    requests: Vec<IR>,
+    concurrency: usize,
+}
+
+impl Client {
+    pub fn batch<I, IR>(&self, requests: I) -> Batch<IR>
+    where
+        I: IntoIterator<Item = IR>,
+        IR: IntoRequest,
+    {
+        Batch {
+            client: self.clone(),
+            requests: requests.into_iter().collect(),
+            concurrency: 8,
+        }
+    }
+}
+
+impl<IR> Batch<IR>
+where
+    IR: IntoRequest,
+    IR::Request: Flat + ServiceRequest + Debug + serde::Serialize,
+{
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub async fn send<R: serde::de::DeserializeOwned>(self) -> BatchReport<R> {
+        let client = self.client;
+        let mut outcomes: Vec<_> = stream::iter(self.requests.into_iter().enumerate())
+            .map(|(index, ir)| {
+                let call = ServiceClient::new(client.clone(), ir).send::<R>();
+                async move { (index, call.await) }
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+        outcomes.sort_by_key(|(index, _)| *index);
+
+        let mut report = BatchReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (index, outcome) in outcomes {
+            match outcome {
+                Ok(Ok(response)) => report.succeeded.push(BatchSuccess { index, response }),
+                Ok(Err(e)) => report.failed.push(BatchFailure {
+                    index,
+                    error: BatchError::Response(e),
+                }),
+                Err(e) => report.failed.push(BatchFailure {
+                    index,
+                    error: BatchError::Request(e),
+                }),
+            }
+        }
+        report
+    }
+}
+
+/// Outcomes of a batch, each in the order of the submitted requests.
+#[derive(Debug)]
+pub struct BatchReport<R> {
+    pub succeeded: Vec<BatchSuccess<R>>,
+    pub failed: Vec<BatchFailure>,
+}
+
+impl<R> BatchReport<R> {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub struct BatchSuccess<R> {
+    /// Position of the request in the submitted iterator.
+    pub index: usize,
+    pub response: Response<R>,
+}
+
+impl<R> BatchSuccess<R> {
+    pub fn request_id(&self) -> Option<&str> {
+        self.response.request_id()
+    }
+}
+
+#[derive(Debug)]
+pub struct BatchFailure {
+    pub index: usize,
+    pub error: BatchError,
+}
+
+impl BatchFailure {
+    pub fn code(&self) -> Option<&str> {
+        match self.error {
+            BatchError::Response(ref e) => e.code(),
+            BatchError::Request(_) => None,
+        }
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        match self.error {
+            BatchError::Response(ref e) => e.request_id(),
+            BatchError::Request(_) => None,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BatchError {
+    #[error(transparent)]
+    Request(Error),
+    #[error(transparent)]
+    Response(ResponseError),
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+
+    use crate::{
+        client::Client,
+        transport::{HttpRequest, HttpResponse, HttpTransport, MockTransport},
+        DescribeProductsRequest, DescribeProductsResponse,
+    };
+
+    const OK: &str = r#"{"Response":{"RequestId":"req-ok","TotalCount":0,"Products":[]}}"#;
+
+    #[tokio::test]
+    async fn test_partial_failure() {
+        let transport = MockTransport::new();
+        transport
+            .respond("DescribeProducts", OK)
+            .respond(
+                "DescribeProducts",
+                r#"{"Response":{"Error":{"Code":"ResourceNotFound.ProductNotExist","Message":"no such product"},"RequestId":"req-err"}}"#,
+            )
+            .respond("DescribeProducts", OK);
+        let client = Client::builder().transport(transport).build().unwrap();
+
+        let requests =
+            (0..3).map(|offset| DescribeProductsRequest::builder().set_offset(Some(offset)));
+        let report = client
+            .batch(requests)
+            .concurrency(1)
+            .send::<DescribeProductsResponse>()
+            .await;
+
+        assert!(!report.is_success());
+        let succeeded: Vec<_> = report.succeeded.iter().map(|s| s.index).collect();
+        assert_eq!(succeeded, vec![0, 2]);
+        assert_eq!(report.succeeded[0].request_id(), Some("req-ok"));
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].index, 1);
+        assert_eq!(
+            report.failed[0].code(),
+            Some("ResourceNotFound.ProductNotExist")
+        );
+        assert_eq!(report.failed[0].request_id(), Some("req-err"));
+    }
+
+    /// Answers after a delay and remembers how many requests overlapped.
+    #[derive(Debug, Default)]
+    struct SlowTransport {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+        sent: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HttpTransport for SlowTransport {
+        async fn send(&self, _: HttpRequest) -> crate::Result<HttpResponse> {
+            struct Guard<'a>(&'a AtomicUsize);
+
+            impl<'a> Drop for Guard<'a> {
+                fn drop(&mut self) {
+                    self.0.fetch_sub(1, Ordering::SeqCst);
+                }
+            }
+
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            let _guard = Guard(&self.in_flight);
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            self.sent.fetch_add(1, Ordering::SeqCst);
+            Ok(HttpResponse::new(200, OK))
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct Shared(Arc<SlowTransport>);
+
+    #[async_trait]
+    impl HttpTransport for Shared {
+        async fn send(&self, request: HttpRequest) -> crate::Result<HttpResponse> {
+            self.0.send(request).await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrency_and_cancellation() {
+        let transport = Shared::default();
+        let client = Client::builder()
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let requests = (0..10).map(|_| DescribeProductsRequest::builder());
+        let report = client
+            .batch(requests)
+            .concurrency(4)
+            .send::<DescribeProductsResponse>()
+            .await;
+        assert_eq!(report.succeeded.len(), 10);
+        assert_eq!(transport.0.max_in_flight.load(Ordering::SeqCst), 4);
+
+        let requests = (0..10).map(|_| DescribeProductsRequest::builder());
+        let batch = client
+            .batch(requests)
+            .concurrency(4)
+            .send::<DescribeProductsResponse>();
+        let _ = tokio::time::timeout(Duration::from_millis(1500), batch).await;
+        assert_eq!(transport.0.in_flight.load(Ordering::SeqCst), 0);
+        assert_eq!(transport.0.sent.load(Ordering::SeqCst), 14);
+    }
+}
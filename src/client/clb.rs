@@ -0,0 +1,252 @@
+use futures::stream::BoxStream;
+
+use crate::{
+    request::clb::*,
+    waiter::{WaitState, Waiter},
+    ResponseResult,
+};
+
+use super::{paginate::paginate, Client, ServiceClient};
+
+pub struct CLBClient {
+    client: Client,
+}
+
+impl CLBClient {
+    pub fn new(client: Client) -> CLBClient {
+        CLBClient { client }
+    }
+}
+
+impl CLBClient {
+    pub fn describe_load_balancers(
+        &self,
+        req: DescribeLoadBalancersRequestBuilder,
+    ) -> ServiceClient<DescribeLoadBalancersRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_load_balancer(
+        &self,
+        req: CreateLoadBalancerRequestBuilder,
+    ) -> ServiceClient<CreateLoadBalancerRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_listener(
+        &self,
+        req: CreateListenerRequestBuilder,
+    ) -> ServiceClient<CreateListenerRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_listeners(
+        &self,
+        req: DescribeListenersRequestBuilder,
+    ) -> ServiceClient<DescribeListenersRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_rule(&self, req: CreateRuleRequestBuilder) -> ServiceClient<CreateRuleRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn register_targets(
+        &self,
+        req: RegisterTargetsRequestBuilder,
+    ) -> ServiceClient<RegisterTargetsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn deregister_targets(
+        &self,
+        req: DeregisterTargetsRequestBuilder,
+    ) -> ServiceClient<DeregisterTargetsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_target_health(
+        &self,
+        req: DescribeTargetHealthRequestBuilder,
+    ) -> ServiceClient<DescribeTargetHealthRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn modify_target_weight(
+        &self,
+        req: ModifyTargetWeightRequestBuilder,
+    ) -> ServiceClient<ModifyTargetWeightRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_task_status(
+        &self,
+        req: DescribeTaskStatusRequestBuilder,
+    ) -> ServiceClient<DescribeTaskStatusRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    /// Every load balancer matching `req`, fetching `page_size` of them per
+    /// call. The offset and limit of `req` are overwritten.
+    pub fn describe_load_balancers_stream(
+        &self,
+        req: DescribeLoadBalancersRequestBuilder,
+        page_size: i64,
+    ) -> BoxStream<'static, ResponseResult<LoadBalancer>> {
+        let client = self.client.clone();
+        let mut offset = 0;
+        paginate(
+            req.set_offset(0).set_limit(page_size),
+            move |req| {
+                ServiceClient::new(client.clone(), req).send::<DescribeLoadBalancersResponse>()
+            },
+            move |req, resp| {
+                offset += resp.load_balancer_set.len() as i64;
+                let next = if resp.load_balancer_set.is_empty() || offset as u64 >= resp.total_count
+                {
+                    None
+                } else {
+                    Some(req.clone().set_offset(offset))
+                };
+                (resp.load_balancer_set, next)
+            },
+        )
+    }
+
+    /// Polls `DescribeTaskStatus` until the task of a mutating call is done.
+    /// `request_id` is the `RequestId` of that call's response.
+    pub fn wait_task(&self, request_id: &str) -> Waiter<DescribeTaskStatusResponse> {
+        let client = self.client.clone();
+        let task_id = request_id.to_string();
+        Waiter::new(
+            move || {
+                let req = DescribeTaskStatusRequest::builder().set_task_id(task_id.clone());
+                ServiceClient::new(client.clone(), req).send()
+            },
+            |resp: &DescribeTaskStatusResponse| match resp.status {
+                TaskStatus::Succeeded => WaitState::Success,
+                TaskStatus::Failed => WaitState::Failure,
+                _ => WaitState::Pending,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        request::clb::*,
+        transport::{mock::client, MockTransport},
+        waiter::WaitError,
+        Flat,
+    };
+
+    fn task(status: i64) -> String {
+        format!(
+            r#"{{"Response":{{"Status":{},"RequestId":"req-status"}}}}"#,
+            status
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_register_targets_and_wait() {
+        let transport = MockTransport::new();
+        transport
+            .respond("RegisterTargets", r#"{"Response":{"RequestId":"req-1"}}"#)
+            .respond("DescribeTaskStatus", task(2))
+            .respond("DescribeTaskStatus", task(2))
+            .respond("DescribeTaskStatus", task(0));
+        let clb = client(&transport).clb();
+
+        let resp = clb
+            .register_targets(
+                RegisterTargetsRequest::builder()
+                    .set_load_balancer_id("lb-1".to_string())
+                    .set_listener_id("lbl-1".to_string())
+                    .set_targets(vec![
+                        Target::instance("ins-1", 8080).weight(20),
+                        Target::eni_ip("10.0.0.8", 8080),
+                    ]),
+            )
+            .send::<RegisterTargetsResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        let requests = transport.requests_for("RegisterTargets");
+        assert_eq!(requests[0].url, "https://clb.tencentcloudapi.com/");
+        assert_eq!(requests[0].headers["X-TC-Version"], "2018-03-17");
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some(
+                &br#"{"LoadBalancerId":"lb-1","ListenerId":"lbl-1","Targets":[{"Port":8080,"InstanceId":"ins-1","Weight":20},{"Port":8080,"EniIp":"10.0.0.8"}]}"#[..]
+            )
+        );
+
+        let status = clb
+            .wait_task(&resp.request_id)
+            .delay(Duration::from_secs(1))
+            .wait()
+            .await
+            .unwrap();
+        assert_eq!(status.status, TaskStatus::Succeeded);
+        let requests = transport.requests_for("DescribeTaskStatus");
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some(&br#"{"TaskId":"req-1"}"#[..])
+        );
+
+        let transport = MockTransport::new();
+        transport.respond("DescribeTaskStatus", task(1));
+        let clb = client(&transport).clb();
+        let err = clb.wait_task("req-2").wait().await.unwrap_err();
+        assert!(matches!(err, WaitError::Failure(_)));
+    }
+
+    #[tokio::test]
+    async fn test_rules_and_target_health() {
+        let transport = MockTransport::new();
+        transport.respond(
+            "DescribeTargetHealth",
+            r#"{"Response":{"LoadBalancers":[{"LoadBalancerId":"lb-1","Listeners":[{"ListenerId":"lbl-1","Protocol":"HTTP","Port":80,"Rules":[{"LocationId":"loc-1","Domain":"example.com","Url":"/","Targets":[{"IP":"10.0.0.8","Port":8080,"HealthStatus":true,"TargetId":"ins-1","HealthStatusDetail":"Alive"},{"IP":"10.0.0.9","Port":8080,"HealthStatus":false,"TargetId":"ins-2","HealthStatusDetail":"Dead"}]}]}]}],"RequestId":"req"}}"#,
+        );
+        let clb = client(&transport).clb();
+
+        let req = CreateRuleRequest {
+            load_balancer_id: Some("lb-1".to_string()),
+            listener_id: Some("lbl-1".to_string()),
+            rules: Some(vec![RuleInput {
+                scheduler: Some("WRR".to_string()),
+                health_check: Some(HealthCheck {
+                    health_switch: Some(1),
+                    http_check_path: Some("/healthz".to_string()),
+                    ..Default::default()
+                }),
+                ..RuleInput::new("example.com", "/")
+            }]),
+        };
+        let hm = req.flat();
+        assert_eq!(hm["Rules.0.Domain"], "example.com");
+        assert_eq!(hm["Rules.0.HealthCheck.HealthSwitch"], "1");
+        assert_eq!(hm["Rules.0.HealthCheck.HttpCheckPath"], "/healthz");
+        assert_eq!(hm.len(), 7);
+
+        let resp = clb
+            .describe_target_health(
+                DescribeTargetHealthRequest::builder()
+                    .set_load_balancer_ids(vec!["lb-1".to_string()]),
+            )
+            .send::<DescribeTargetHealthResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        let unhealthy: Vec<_> = resp.load_balancers[0]
+            .targets()
+            .filter(|(_, target)| !target.health_status)
+            .map(|(listener_id, target)| (listener_id, target.target_id.as_str()))
+            .collect();
+        assert_eq!(unhealthy, vec![("lbl-1", "ins-2")]);
+    }
+}
@@ -0,0 +1,426 @@
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+use http::Method;
+
+use crate::{ClientProfile, Credential, Error, HTTProfile, Region, Scheme, SignMethod};
+
+use super::ClientBuilder;
+
+const ENV_PREFIX: &str = "TENCENTCLOUD_";
+
+impl ClientBuilder {
+    /// Reads the `TENCENTCLOUD_*` environment variables, e.g.
+    /// `TENCENTCLOUD_SECRET_ID`, `TENCENTCLOUD_SECRET_KEY`, `TENCENTCLOUD_REGION`,
+    /// `TENCENTCLOUD_SCHEME`, `TENCENTCLOUD_ENDPOINT`, `TENCENTCLOUD_TIMEOUT` or
+    /// `TENCENTCLOUD_LANGUAGE`. Unset variables keep their defaults. Requests
+    /// are POST requests signed with `TC3-HMAC-SHA256`, so another
+    /// `TENCENTCLOUD_HTTP_METHOD` or `TENCENTCLOUD_SIGN_METHOD` is an error.
+    pub fn from_env() -> crate::Result<ClientBuilder> {
+        let vars = std::env::vars().filter_map(|(key, value)| {
+            let key = key.strip_prefix(ENV_PREFIX)?;
+            Some((key.to_string(), value))
+        });
+        ClientBuilder::default().apply(vars, "environment variable TENCENTCLOUD_")
+    }
+
+    /// Reads the section `profile` of an INI or flat TOML file, or a tccli
+    /// `~/.tccli/<profile>.configure` JSON file together with the
+    /// `<profile>.credential` file next to it. A tccli file of another
+    /// profile is an `Error::Configuration`.
+    pub fn from_config_file<P: AsRef<Path>>(
+        path: P,
+        profile: &str,
+    ) -> crate::Result<ClientBuilder> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let origin = format!("{}: ", path.display());
+        if !content.trim_start().starts_with('{') {
+            let values = ini_section(&content, profile).ok_or_else(|| {
+                Error::Configuration(format!(
+                    "profile {} not found in {}",
+                    profile,
+                    path.display()
+                ))
+            })?;
+            return ClientBuilder::default().apply(values, &origin);
+        }
+
+        if path.file_stem() != Some(profile.as_ref()) {
+            return Err(Error::Configuration(format!(
+                "{} is not the tccli file of profile {}, expected {}.configure",
+                path.display(),
+                profile,
+                profile
+            )));
+        }
+        let mut values = json_object(&content, "_sys_param", path)?;
+        let credential = path.with_extension("credential");
+        if path.extension() == Some("configure".as_ref()) && credential.exists() {
+            values.extend(json_object(
+                &std::fs::read_to_string(&credential)?,
+                "",
+                &credential,
+            )?);
+        }
+        ClientBuilder::default().apply(values, &origin)
+    }
+
+    fn apply<I>(mut self, values: I, origin: &str) -> crate::Result<ClientBuilder>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        fn parse<T: FromStr>(key: &str, value: &str, origin: &str) -> crate::Result<T>
+        where
+            T::Err: std::fmt::Display,
+        {
+            value
+                .parse()
+                .map_err(|e| Error::Configuration(format!("{}{}: {}", origin, key, e)))
+        }
+
+        let unsupported = |key: &str, value: &str| {
+            Error::Configuration(format!("{}{}: {} is not supported", origin, key, value))
+        };
+
+        let (mut secret_id, mut secret_key, mut token) = (None, None, None);
+        for (key, value) in values {
+            let value = value.trim();
+            let (http, client) = (&mut self.http_profile, &mut self.client_profile);
+            // `SECRET_ID`, `secret_id` and tccli's `secretId` are the same key
+            match key.replace('_', "").to_lowercase().as_str() {
+                "secretid" => secret_id = Some(value.to_string()),
+                "secretkey" => secret_key = Some(value.to_string()),
+                "token" | "sessiontoken" if !value.is_empty() => token = Some(value.to_string()),
+                "region" => self.region = parse::<Region>(&key, value, origin)?,
+                "scheme" => http.scheme = parse::<Scheme>(&key, value, origin)?,
+                "endpoint" => http.endpoint = value.to_string(),
+                "rootdomain" => http.root_domain = value.to_string(),
+                "timeout" => http.timeout = parse(&key, value, origin)?,
+                "method" | "httpmethod" => {
+                    http.method = parse(&key, &value.to_uppercase(), origin)?;
+                    if http.method != Method::POST {
+                        return Err(unsupported(&key, value));
+                    }
+                }
+                "signmethod" => {
+                    client.sing_method = parse(&key, value, origin)?;
+                    if !matches!(client.sing_method, SignMethod::Tc3HmacSha256) {
+                        return Err(unsupported(&key, value));
+                    }
+                }
+                "language" => client.language = parse(&key, value, origin)?,
+                "unsignedpayload" => client.unsigned_payload = parse(&key, value, origin)?,
+                "debug" => client.debug = parse(&key, value, origin)?,
+                _ => {}
+            }
+        }
+        if secret_id.is_some() || secret_key.is_some() {
+            let mut credential = Credential::builder()
+                .access_key(secret_id.as_deref().unwrap_or_default())
+                .secret_key(secret_key.as_deref().unwrap_or_default());
+            if let Some(ref token) = token {
+                credential = credential.token(token);
+            }
+            self.credential = credential.build();
+        }
+        Ok(self)
+    }
+}
+
+/// Rejects the settings no request can be made with: requests are sent with
+/// POST and signed with `TC3-HMAC-SHA256` only.
+pub(super) fn check_profile(client: &ClientProfile, http: &HTTProfile) -> crate::Result<()> {
+    if http.method != Method::POST {
+        return Err(Error::Configuration(format!(
+            "http method {} is not supported, only POST is",
+            http.method
+        )));
+    }
+    if !matches!(client.sing_method, SignMethod::Tc3HmacSha256) {
+        return Err(Error::Configuration(format!(
+            "sign method {} is not supported, only TC3-HMAC-SHA256 is",
+            client.sing_method.as_ref()
+        )));
+    }
+    Ok(())
+}
+
+fn ini_section(content: &str, profile: &str) -> Option<Vec<(String, String)>> {
+    let (mut in_section, mut found) = (false, false);
+    let mut values = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if line.starts_with('[') && line.ends_with(']') {
+            in_section = line[1..line.len() - 1].trim() == profile;
+            found |= in_section;
+            continue;
+        }
+        if in_section {
+            if let Some((key, value)) = line.split_once('=') {
+                values.push((key.trim().to_string(), unquote(value.trim()).to_string()));
+            }
+        }
+    }
+    if found {
+        Some(values)
+    } else {
+        None
+    }
+}
+
+/// The text between the quotes of a TOML style `"value"` or `'value'`,
+/// dropping what follows such as a comment; other values are kept as is.
+fn unquote(value: &str) -> &str {
+    let quote = match value.chars().next() {
+        Some(quote @ '"') | Some(quote @ '\'') => quote,
+        _ => return value,
+    };
+    match value[1..].find(quote) {
+        Some(end) => &value[1..end + 1],
+        None => value,
+    }
+}
+
+/// Flattens the scalar members of `content[key]` (or `content` itself when
+/// `key` is empty) into strings.
+fn json_object(content: &str, key: &str, path: &Path) -> crate::Result<HashMap<String, String>> {
+    let value: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| Error::Configuration(format!("{}: {}", path.display(), e)))?;
+    let object = if key.is_empty() {
+        Some(&value)
+    } else {
+        value.get(key)
+    };
+    let object = object.and_then(|o| o.as_object()).ok_or_else(|| {
+        Error::Configuration(format!("{}: missing object {}", path.display(), key))
+    })?;
+    Ok(object
+        .iter()
+        .filter_map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                _ => return None,
+            };
+            Some((key.clone(), value))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use http::Method;
+
+    use crate::{
+        transport::MockTransport, DeleteDeviceRequest, DeleteDeviceResponse,
+        DescribeProductsRequest, DescribeProductsResponse, HTTProfile, Language, Region, Scheme,
+        SignMethod,
+    };
+
+    use super::ClientBuilder;
+
+    fn write(name: &str, content: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tencentcloud-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_apply() {
+        let values = vec![
+            ("SECRET_ID", "id"),
+            ("SECRET_KEY", "key"),
+            ("REGION", "ap-beijing"),
+            ("SCHEME", "http"),
+            ("ENDPOINT", "iotcloud.internal.tencentcloudapi.com"),
+            ("TIMEOUT", "5"),
+            ("HTTP_METHOD", "post"),
+            ("LANGUAGE", "en-US"),
+            ("UNSIGNED_PAYLOAD", "true"),
+        ];
+        let builder = ClientBuilder::default()
+            .apply(
+                values
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string())),
+                "",
+            )
+            .unwrap();
+        assert_eq!(builder.region.as_ref(), Region::APBeijing.as_ref());
+        assert_eq!(builder.credential.access_key(), "id");
+        assert_eq!(builder.credential.secret_key(), "key");
+        assert!(matches!(builder.http_profile.scheme, Scheme::HTTP));
+        assert_eq!(builder.http_profile.timeout, 5);
+        assert_eq!(builder.http_profile.method, Method::POST);
+        assert!(matches!(builder.client_profile.language, Language::ENUS));
+        assert!(builder.client_profile.unsigned_payload);
+    }
+
+    #[test]
+    fn test_readable_errors() {
+        let err = ClientBuilder::default()
+            .apply(
+                vec![("REGION".to_string(), "ap-mars".to_string())],
+                "environment variable TENCENTCLOUD_",
+            )
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid configuration: environment variable TENCENTCLOUD_REGION: unknown region: ap-mars"
+        );
+
+        let path = write("bad.ini", "[default]\nscheme = ftp\n");
+        let err = ClientBuilder::from_config_file(&path, "default")
+            .err()
+            .unwrap();
+        assert!(err.to_string().ends_with("scheme: unknown scheme: ftp"));
+
+        let err = ClientBuilder::from_config_file(&path, "missing")
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("profile missing not found"));
+
+        for (key, value) in &[("HTTP_METHOD", "get"), ("SIGN_METHOD", "HmacSHA1")] {
+            let err = ClientBuilder::default()
+                .apply(
+                    vec![(key.to_string(), value.to_string())],
+                    "environment variable TENCENTCLOUD_",
+                )
+                .err()
+                .unwrap();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "invalid configuration: environment variable TENCENTCLOUD_{}: {} is not supported",
+                    key, value
+                )
+            );
+        }
+        let err = ClientBuilder::default()
+            .http_profile(HTTProfile {
+                method: Method::GET,
+                ..Default::default()
+            })
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, crate::Error::Configuration(_)));
+    }
+
+    #[test]
+    fn test_ini_file() {
+        let path = write(
+            "config.ini",
+            "# comment\n[default]\nregion = ap-guangzhou-1\n\n[prod]\nsecretId = id\nsecretKey = key\nregion = ap-shanghai-1\ntimeout = 10\n",
+        );
+        let builder = ClientBuilder::from_config_file(&path, "prod").unwrap();
+        assert_eq!(builder.region.as_ref(), "ap-shanghai-1");
+        assert_eq!(builder.credential.access_key(), "id");
+        assert_eq!(builder.http_profile.timeout, 10);
+
+        let path = write(
+            "config.toml",
+            "[default]\nregion = \"ap-guangzhou\" # comment\nscheme = 'http'\nendpoint = \"\"\n",
+        );
+        let builder = ClientBuilder::from_config_file(&path, "default").unwrap();
+        assert_eq!(builder.region.as_ref(), "ap-guangzhou");
+        assert!(matches!(builder.http_profile.scheme, Scheme::HTTP));
+        assert_eq!(builder.http_profile.endpoint, "");
+    }
+
+    #[test]
+    fn test_from_env() {
+        // the only test touching TENCENTCLOUD_* variables
+        std::env::set_var("TENCENTCLOUD_SECRET_ID", "env-id");
+        std::env::set_var("TENCENTCLOUD_SECRET_KEY", "env-key");
+        std::env::set_var("TENCENTCLOUD_REGION", "ap-chengdu");
+        std::env::set_var("TENCENTCLOUD_SIGN_METHOD", "TC3-HMAC-SHA256");
+        let builder = ClientBuilder::from_env();
+        for key in &["SECRET_ID", "SECRET_KEY", "REGION", "SIGN_METHOD"] {
+            std::env::remove_var(format!("TENCENTCLOUD_{}", key));
+        }
+        let builder = builder.unwrap();
+        assert_eq!(builder.region.as_ref(), "ap-chengdu");
+        assert_eq!(builder.credential.access_key(), "env-id");
+        assert_eq!(builder.credential.secret_key(), "env-key");
+        assert!(matches!(
+            builder.client_profile.sing_method,
+            SignMethod::Tc3HmacSha256
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_profile_reaches_requests() {
+        let path = write(
+            "local.ini",
+            "[local]\nscheme = http\nrootdomain = internal.example.com\nlanguage = en-US\n",
+        );
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "DescribeProducts",
+                r#"{"Response":{"TotalCount":0,"Products":[],"RequestId":"req-1"}}"#,
+            )
+            .respond("DeleteDevice", r#"{"Response":{"RequestId":"req-2"}}"#);
+        let client = ClientBuilder::from_config_file(&path, "local")
+            .unwrap()
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        client
+            .iotcloud()
+            .describe_products(DescribeProductsRequest::builder())
+            .send::<DescribeProductsResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        client
+            .iotcloud()
+            .delete_device(
+                DeleteDeviceRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_device_name("lamp-1".to_string()),
+            )
+            .send::<DeleteDeviceResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        for request in transport.requests() {
+            assert_eq!(request.url, "http://iotcloud.internal.example.com/");
+            assert_eq!(request.headers["X-TC-Language"], "en-US");
+        }
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_tccli_file() {
+        let path = write(
+            "default.configure",
+            r#"{"_sys_param": {"arrayCount": 10, "output": "json", "region": "ap-hongkong-1"}}"#,
+        );
+        write(
+            "default.credential",
+            r#"{"secretId": "id", "secretKey": "key", "token": ""}"#,
+        );
+        let builder = ClientBuilder::from_config_file(&path, "default").unwrap();
+        assert_eq!(builder.region.as_ref(), "ap-hongkong-1");
+        assert_eq!(builder.credential.access_key(), "id");
+        assert_eq!(builder.credential.token(), None);
+
+        let err = ClientBuilder::from_config_file(&path, "prod")
+            .err()
+            .unwrap();
+        assert!(matches!(err, crate::Error::Configuration(_)));
+        assert!(err
+            .to_string()
+            .ends_with("is not the tccli file of profile prod, expected prod.configure"));
+    }
+}
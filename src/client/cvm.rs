@@ -0,0 +1,425 @@
+use std::future::Future;
+
+use futures::stream::BoxStream;
+
+use crate::{
+    request::cvm::*,
+    response::Response,
+    waiter::{WaitState, Waiter},
+    Error, ResponseResult,
+};
+
+use super::{paginate::paginate, Client, ServiceClient};
+
+pub struct CVMClient {
+    client: Client,
+}
+
+impl CVMClient {
+    pub fn new(client: Client) -> CVMClient {
+        CVMClient { client }
+    }
+}
+
+impl CVMClient {
+    pub fn describe_instances(
+        &self,
+        req: DescribeInstancesRequestBuilder,
+    ) -> ServiceClient<DescribeInstancesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn run_instances(
+        &self,
+        req: RunInstancesRequestBuilder,
+    ) -> ServiceClient<RunInstancesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn start_instances(
+        &self,
+        req: StartInstancesRequestBuilder,
+    ) -> ServiceClient<StartInstancesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn stop_instances(
+        &self,
+        req: StopInstancesRequestBuilder,
+    ) -> ServiceClient<StopInstancesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn reboot_instances(
+        &self,
+        req: RebootInstancesRequestBuilder,
+    ) -> ServiceClient<RebootInstancesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn terminate_instances(
+        &self,
+        req: TerminateInstancesRequestBuilder,
+    ) -> ServiceClient<TerminateInstancesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn reset_instance(
+        &self,
+        req: ResetInstanceRequestBuilder,
+    ) -> ServiceClient<ResetInstanceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_images(
+        &self,
+        req: DescribeImagesRequestBuilder,
+    ) -> ServiceClient<DescribeImagesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_instance_type_configs(
+        &self,
+        req: DescribeInstanceTypeConfigsRequestBuilder,
+    ) -> ServiceClient<DescribeInstanceTypeConfigsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_zones(
+        &self,
+        req: DescribeZonesRequestBuilder,
+    ) -> ServiceClient<DescribeZonesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    /// Every instance matching `req`, fetching `page_size` of them per call.
+    /// The offset and limit of `req` are overwritten.
+    pub fn describe_instances_stream(
+        &self,
+        req: DescribeInstancesRequestBuilder,
+        page_size: i64,
+    ) -> BoxStream<'static, ResponseResult<Instance>> {
+        let client = self.client.clone();
+        let mut offset = 0;
+        paginate(
+            req.set_offset(0).set_limit(page_size),
+            move |req| ServiceClient::new(client.clone(), req).send::<DescribeInstancesResponse>(),
+            move |req, resp| {
+                offset += resp.instance_set.len() as i64;
+                let next = if resp.instance_set.is_empty() || offset >= resp.total_count {
+                    None
+                } else {
+                    Some(req.clone().set_offset(offset))
+                };
+                (resp.instance_set, next)
+            },
+        )
+    }
+
+    /// Every image matching `req`, fetching `page_size` of them per call.
+    /// The offset and limit of `req` are overwritten.
+    pub fn describe_images_stream(
+        &self,
+        req: DescribeImagesRequestBuilder,
+        page_size: u64,
+    ) -> BoxStream<'static, ResponseResult<Image>> {
+        let client = self.client.clone();
+        let mut offset = 0;
+        paginate(
+            req.set_offset(0).set_limit(page_size),
+            move |req| ServiceClient::new(client.clone(), req).send::<DescribeImagesResponse>(),
+            move |req, resp| {
+                offset += resp.image_set.len() as u64;
+                let next = if resp.image_set.is_empty() || offset >= resp.total_count as u64 {
+                    None
+                } else {
+                    Some(req.clone().set_offset(offset))
+                };
+                (resp.image_set, next)
+            },
+        )
+    }
+
+    /// Polls `DescribeInstances` until every instance of `instance_ids` is in
+    /// `state`, failing if one of them fails to launch. The instances are
+    /// described `MAX_DESCRIBE_INSTANCES` at a time, and each response holds
+    /// the instances of every call. An empty `instance_ids` fails with
+    /// `Error::InvalidRequest`.
+    pub fn wait_instances(
+        &self,
+        instance_ids: Vec<String>,
+        state: InstanceState,
+    ) -> Waiter<DescribeInstancesResponse> {
+        let client = self.client.clone();
+        let ids = instance_ids.clone();
+        Waiter::new(
+            move || {
+                let calls: Vec<_> = ids
+                    .chunks(MAX_DESCRIBE_INSTANCES)
+                    .map(|ids| {
+                        let req = DescribeInstancesRequest::builder()
+                            .set_instance_ids(ids.to_vec())
+                            .set_limit(ids.len() as i64);
+                        ServiceClient::new(client.clone(), req).send()
+                    })
+                    .collect();
+                describe_all(calls)
+            },
+            move |resp: &DescribeInstancesResponse| {
+                let instances = &resp.instance_set;
+                if instances
+                    .iter()
+                    .any(|instance| instance.instance_state == InstanceState::LaunchFailed)
+                    && state != InstanceState::LaunchFailed
+                {
+                    return WaitState::Failure;
+                }
+                let done = instance_ids.iter().all(|id| {
+                    instances.iter().any(|instance| {
+                        &instance.instance_id == id && instance.instance_state == state
+                    })
+                });
+                if done {
+                    WaitState::Success
+                } else {
+                    WaitState::Pending
+                }
+            },
+        )
+    }
+}
+
+/// Makes the `DescribeInstances` calls one after the other, returning the
+/// response of the last one with the instances of all of them.
+async fn describe_all<F>(calls: Vec<F>) -> ResponseResult<Response<DescribeInstancesResponse>>
+where
+    F: Future<Output = ResponseResult<Response<DescribeInstancesResponse>>>,
+{
+    let mut instances = Vec::new();
+    let mut last = None;
+    for call in calls {
+        let resp = match call.await? {
+            Ok(resp) => resp,
+            Err(e) => return Ok(Err(e)),
+        };
+        let request_id = resp.request_id().map(str::to_string);
+        let mut resp = resp.into_inner();
+        instances.append(&mut resp.instance_set);
+        last = Some((resp, request_id));
+    }
+    match last {
+        Some((mut resp, request_id)) => {
+            resp.total_count = instances.len() as i64;
+            resp.instance_set = instances;
+            Ok(Ok(Response::new(resp, request_id)))
+        }
+        None => Err(Error::InvalidRequest(
+            "DescribeInstances: no instance ids to wait for".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    use crate::{
+        request::cvm::*,
+        transport::{mock::client, MockTransport},
+        waiter::WaitError,
+        Flat,
+    };
+
+    fn instances(states: &[(&str, &str)], total: usize) -> String {
+        let set: Vec<_> = states
+            .iter()
+            .map(|(id, state)| serde_json::json!({"InstanceId": id, "InstanceState": state}))
+            .collect();
+        serde_json::json!({
+            "Response": {"TotalCount": total, "InstanceSet": set, "RequestId": "req"}
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_flat_nested() {
+        let req = DescribeInstancesRequest {
+            filters: Some(vec![
+                InstanceFilter::Zone("ap-guangzhou-3".to_string()).into(),
+                Filter::new("instance-state", vec!["RUNNING", "STOPPED"]),
+            ]),
+            ..Default::default()
+        };
+        let hm = req.flat();
+        assert_eq!(hm.len(), 5);
+        assert_eq!(hm["Filters.0.Name"], "zone");
+        assert_eq!(hm["Filters.0.Values.0"], "ap-guangzhou-3");
+        assert_eq!(hm["Filters.1.Name"], "instance-state");
+        assert_eq!(hm["Filters.1.Values.1"], "STOPPED");
+
+        let req = RunInstancesRequest {
+            placement: Some(Placement {
+                zone: "ap-guangzhou-3".to_string(),
+                project_id: None,
+            }),
+            data_disks: Some(vec![DataDisk {
+                disk_size: 50,
+                delete_with_instance: Some(true),
+                ..Default::default()
+            }]),
+            login_settings: Some(LoginSettings {
+                key_ids: vec!["skey-1".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let hm = req.flat();
+        assert_eq!(hm.len(), 4);
+        assert_eq!(hm["Placement.Zone"], "ap-guangzhou-3");
+        assert_eq!(hm["DataDisks.0.DiskSize"], "50");
+        assert_eq!(hm["DataDisks.0.DeleteWithInstance"], "true");
+        assert_eq!(hm["LoginSettings.KeyIds.0"], "skey-1");
+    }
+
+    #[tokio::test]
+    async fn test_describe_instances_stream() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "DescribeInstances",
+                instances(&[("ins-1", "RUNNING"), ("ins-2", "RUNNING")], 3),
+            )
+            .respond("DescribeInstances", instances(&[("ins-3", "STOPPED")], 3));
+        let cvm = client(&transport).cvm();
+
+        let req = DescribeInstancesRequest::builder()
+            .filter(InstanceFilter::Tag {
+                key: "team".to_string(),
+                value: "iot".to_string(),
+            })
+            .set_offset(40);
+        let ids: Vec<_> = cvm
+            .describe_instances_stream(req, 2)
+            .map(|instance| instance.unwrap().unwrap().instance_id)
+            .collect()
+            .await;
+        assert_eq!(ids, vec!["ins-1", "ins-2", "ins-3"]);
+
+        let requests = transport.requests_for("DescribeInstances");
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url, "https://cvm.tencentcloudapi.com/");
+        assert_eq!(requests[0].headers["X-TC-Version"], "2017-03-12");
+        assert_eq!(requests[0].headers["X-TC-Region"], "ap-guangzhou");
+        assert_eq!(
+            requests[1].body.as_deref(),
+            Some(
+                &br#"{"Filters":[{"Name":"tag:team","Values":["iot"]}],"Offset":2,"Limit":2}"#[..]
+            )
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_run_and_wait_instances() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "RunInstances",
+                r#"{"Response":{"InstanceIdSet":["ins-1","ins-2"],"RequestId":"req"}}"#,
+            )
+            .respond("DescribeInstances", instances(&[("ins-1", "PENDING")], 1))
+            .respond(
+                "DescribeInstances",
+                instances(&[("ins-1", "RUNNING"), ("ins-2", "PENDING")], 2),
+            )
+            .respond(
+                "DescribeInstances",
+                instances(&[("ins-1", "RUNNING"), ("ins-2", "RUNNING")], 2),
+            );
+        let cvm = client(&transport).cvm();
+
+        let resp = cvm
+            .run_instances(
+                RunInstancesRequest::builder()
+                    .set_placement(Placement {
+                        zone: "ap-guangzhou-3".to_string(),
+                        project_id: Some(0),
+                    })
+                    .set_instance_type("S5.MEDIUM4".to_string())
+                    .set_image_id("img-abcd1234".to_string())
+                    .set_instance_count(2)
+                    .set_login_settings(LoginSettings {
+                        password: Some("hunter2".to_string()),
+                        ..Default::default()
+                    }),
+            )
+            .send::<RunInstancesResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        let body = transport.requests_for("RunInstances")[0]
+            .body
+            .clone()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            r#"{"Placement":{"Zone":"ap-guangzhou-3","ProjectId":0},"InstanceType":"S5.MEDIUM4","ImageId":"img-abcd1234","InstanceCount":2,"LoginSettings":{"Password":"hunter2"}}"#
+        );
+
+        let resp = cvm
+            .wait_instances(resp.instance_id_set.clone(), InstanceState::Running)
+            .delay(Duration::from_secs(5))
+            .wait()
+            .await
+            .unwrap();
+        assert_eq!(resp.instance_set.len(), 2);
+        assert_eq!(transport.requests_for("DescribeInstances").len(), 3);
+
+        transport.respond(
+            "DescribeInstances",
+            instances(&[("ins-3", "LAUNCH_FAILED")], 1),
+        );
+        let err = cvm
+            .wait_instances(vec!["ins-3".to_string()], InstanceState::Running)
+            .wait()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WaitError::Failure(_)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_many_instances() {
+        let transport = MockTransport::new();
+        let cvm = client(&transport).cvm();
+        let err = cvm
+            .wait_instances(vec![], InstanceState::Running)
+            .wait()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            WaitError::Request(crate::Error::InvalidRequest(_))
+        ));
+        assert!(transport.requests().is_empty());
+
+        let ids: Vec<_> = (0..150).map(|i| format!("ins-{}", i)).collect();
+        let running: Vec<_> = ids.iter().map(|id| (id.as_str(), "RUNNING")).collect();
+        transport
+            .respond("DescribeInstances", instances(&running[..100], 150))
+            .respond("DescribeInstances", instances(&running[100..], 150));
+        let resp = cvm
+            .wait_instances(ids, InstanceState::Running)
+            .wait()
+            .await
+            .unwrap();
+        assert_eq!((resp.instance_set.len(), resp.total_count), (150, 150));
+        let requests = transport.requests_for("DescribeInstances");
+        assert_eq!(requests.len(), 2);
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[1].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["InstanceIds"].as_array().unwrap().len(), 50);
+        assert_eq!(body["InstanceIds"][0], "ins-100");
+        assert_eq!(body["Limit"], 50);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashMap, future::Future};
+
+use futures::future::join_all;
+
+use crate::Region;
+
+use super::Client;
+
+impl Client {
+    pub fn region(&self) -> Region {
+        self.config.region
+    }
+
+    /// A client for `region` sharing the transport, its connection pool and
+    /// the credential of this one.
+    pub fn with_region(&self, region: Region) -> Client {
+        let mut client = self.clone();
+        client.config.region = region;
+        client
+    }
+
+    /// Runs `f` against a client for each of `regions` concurrently and
+    /// collects whatever it returns, errors included, keyed by region.
+    ///
+    /// ```no_run
+    /// # async fn inventory(client: tencentcloud::client::Client) {
+    /// use tencentcloud::{DescribeProductsRequest, DescribeProductsResponse, Region};
+    ///
+    /// let products = client
+    ///     .fan_out(vec![Region::APGuangzhou, Region::APShanghai], |client| async move {
+    ///         client
+    ///             .iotcloud()
+    ///             .describe_products(DescribeProductsRequest::builder())
+    ///             .send::<DescribeProductsResponse>()
+    ///             .await
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn fan_out<I, F, Fut>(&self, regions: I, f: F) -> HashMap<Region, Fut::Output>
+    where
+        I: IntoIterator<Item = Region>,
+        F: Fn(Client) -> Fut,
+        Fut: Future,
+    {
+        let calls = regions.into_iter().map(|region| {
+            let call = f(self.with_region(region));
+            async move { (region, call.await) }
+        });
+        join_all(calls).await.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        client::Client, transport::MockTransport, DescribeProductsRequest,
+        DescribeProductsResponse, Region,
+    };
+
+    #[tokio::test]
+    async fn test_fan_out() {
+        let transport = MockTransport::new();
+        transport.respond(
+            "DescribeProducts",
+            r#"{"Response":{"RequestId":"req-1","TotalCount":0,"Products":[]}}"#,
+        );
+        let client = Client::builder()
+            .region(Region::APBeijing)
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let regions = vec![Region::APGuangzhou, Region::APShanghai, Region::APHongkong];
+        let results = client
+            .fan_out(regions.clone(), |client| async move {
+                if client.region() == Region::APHongkong {
+                    return Err("unavailable");
+                }
+                let resp = client
+                    .iotcloud()
+                    .describe_products(DescribeProductsRequest::builder())
+                    .send::<DescribeProductsResponse>()
+                    .await
+                    .unwrap()
+                    .unwrap();
+                Ok(resp.total_count)
+            })
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[&Region::APGuangzhou], Ok(0));
+        assert_eq!(results[&Region::APShanghai], Ok(0));
+        assert_eq!(results[&Region::APHongkong], Err("unavailable"));
+        assert_eq!(client.region(), Region::APBeijing);
+        let mut sent: Vec<_> = transport
+            .requests()
+            .iter()
+            .map(|req| req.headers["X-TC-Region"].to_str().unwrap().to_string())
+            .collect();
+        sent.sort();
+        assert_eq!(sent, vec!["ap-guangzhou", "ap-shanghai"]);
+    }
+}
@@ -0,0 +1,365 @@
+use futures::stream::BoxStream;
+
+use crate::{request::iotexplorer::*, ResponseResult};
+
+use super::{paginate::paginate, Client, ServiceClient};
+
+pub struct IOTExplorerClient {
+    client: Client,
+}
+
+impl IOTExplorerClient {
+    pub fn new(client: Client) -> IOTExplorerClient {
+        IOTExplorerClient { client }
+    }
+}
+
+impl IOTExplorerClient {
+    pub fn create_project(
+        &self,
+        req: CreateProjectRequestBuilder,
+    ) -> ServiceClient<CreateProjectRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_project(
+        &self,
+        req: DescribeProjectRequestBuilder,
+    ) -> ServiceClient<DescribeProjectRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn get_project_list(
+        &self,
+        req: GetProjectListRequestBuilder,
+    ) -> ServiceClient<GetProjectListRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn modify_project(
+        &self,
+        req: ModifyProjectRequestBuilder,
+    ) -> ServiceClient<ModifyProjectRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_project(
+        &self,
+        req: DeleteProjectRequestBuilder,
+    ) -> ServiceClient<DeleteProjectRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_studio_product(
+        &self,
+        req: CreateStudioProductRequestBuilder,
+    ) -> ServiceClient<CreateStudioProductRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_studio_product(
+        &self,
+        req: DescribeStudioProductRequestBuilder,
+    ) -> ServiceClient<DescribeStudioProductRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn get_studio_product_list(
+        &self,
+        req: GetStudioProductListRequestBuilder,
+    ) -> ServiceClient<GetStudioProductListRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn modify_studio_product(
+        &self,
+        req: ModifyStudioProductRequestBuilder,
+    ) -> ServiceClient<ModifyStudioProductRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_studio_product(
+        &self,
+        req: DeleteStudioProductRequestBuilder,
+    ) -> ServiceClient<DeleteStudioProductRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_model_definition(
+        &self,
+        req: DescribeModelDefinitionRequestBuilder,
+    ) -> ServiceClient<DescribeModelDefinitionRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn modify_model_definition(
+        &self,
+        req: ModifyModelDefinitionRequestBuilder,
+    ) -> ServiceClient<ModifyModelDefinitionRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_device(
+        &self,
+        req: CreateDeviceRequestBuilder,
+    ) -> ServiceClient<CreateDeviceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_device(
+        &self,
+        req: DescribeDeviceRequestBuilder,
+    ) -> ServiceClient<DescribeDeviceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn get_device_list(
+        &self,
+        req: GetDeviceListRequestBuilder,
+    ) -> ServiceClient<GetDeviceListRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_device(
+        &self,
+        req: DeleteDeviceRequestBuilder,
+    ) -> ServiceClient<DeleteDeviceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn control_device_data(
+        &self,
+        req: ControlDeviceDataRequestBuilder,
+    ) -> ServiceClient<ControlDeviceDataRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn call_device_action_sync(
+        &self,
+        req: CallDeviceActionSyncRequestBuilder,
+    ) -> ServiceClient<CallDeviceActionSyncRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn call_device_action_async(
+        &self,
+        req: CallDeviceActionAsyncRequestBuilder,
+    ) -> ServiceClient<CallDeviceActionAsyncRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_device_data(
+        &self,
+        req: DescribeDeviceDataRequestBuilder,
+    ) -> ServiceClient<DescribeDeviceDataRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_device_data_history(
+        &self,
+        req: DescribeDeviceDataHistoryRequestBuilder,
+    ) -> ServiceClient<DescribeDeviceDataHistoryRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    /// Every past value of a property matching `req`, following the
+    /// `Context` cursor until `Listover`.
+    pub fn describe_device_data_history_stream(
+        &self,
+        req: DescribeDeviceDataHistoryRequestBuilder,
+    ) -> BoxStream<'static, ResponseResult<DeviceDataHistoryItem>> {
+        let client = self.client.clone();
+        paginate(
+            req,
+            move |req| {
+                ServiceClient::new(client.clone(), req).send::<DescribeDeviceDataHistoryResponse>()
+            },
+            |req, resp| {
+                let next = if resp.list_over || resp.context.is_empty() {
+                    None
+                } else {
+                    Some(req.clone().set_context(resp.context))
+                };
+                (resp.results, next)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::StreamExt;
+
+    use crate::{
+        request::iotexplorer::*,
+        transport::{
+            mock::{body, client},
+            MockTransport,
+        },
+    };
+
+    const TEMPLATE: &str = r#"{
+        "version": "1.0",
+        "profile": {"ProductId": "ABCDEFGHIJ", "CategoryId": "1"},
+        "properties": [
+            {"id": "power_switch", "name": "switch", "desc": "", "mode": "rw", "define": {"type": "bool", "mapping": {"0": "off", "1": "on"}}, "required": true},
+            {"id": "brightness", "name": "brightness", "desc": "", "mode": "rw", "define": {"type": "int", "min": "0", "max": "100", "start": "1", "step": "1", "unit": "%"}, "required": false, "isUsed": 1},
+            {"id": "schedule", "name": "schedule", "desc": "", "mode": "rw", "define": {"type": "array", "arrayInfo": {"type": "struct", "specs": [{"id": "at", "name": "at", "dataType": {"type": "timestamp"}}]}}, "required": false},
+            {"id": "location", "name": "location", "desc": "", "mode": "r", "define": {"type": "geopoint", "precision": 6}, "required": false}
+        ],
+        "events": [
+            {"id": "overheat", "name": "overheat", "desc": "", "type": "alert", "params": [{"id": "temperature", "name": "temperature", "define": {"type": "float", "min": "0", "max": "200", "start": "0", "step": "1", "unit": "C"}}], "required": false}
+        ],
+        "actions": [
+            {"id": "blink", "name": "blink", "desc": "", "input": [{"id": "times", "name": "times", "define": {"type": "int", "min": "1", "max": "10", "start": "1", "step": "1", "unit": ""}}], "output": [{"id": "done", "name": "done", "define": {"type": "bool", "mapping": {"0": "no", "1": "yes"}}}], "required": false, "timeout": 5}
+        ],
+        "category": "light"
+    }"#;
+
+    #[tokio::test]
+    async fn test_data_template() {
+        let transport = MockTransport::new();
+        let model = serde_json::json!({
+            "Response": {
+                "Model": {"ProductId": "ABCDEFGHIJ", "ModelDefine": TEMPLATE, "UpdateTime": 1, "CreateTime": 1},
+                "RequestId": "req-1"
+            }
+        });
+        transport
+            .respond("DescribeModelDefinition", model.to_string())
+            .respond(
+                "ModifyModelDefinition",
+                r#"{"Response":{"RequestId":"req-2"}}"#,
+            );
+        let iotexplorer = client(&transport).iotexplorer();
+
+        let resp = iotexplorer
+            .describe_model_definition(
+                DescribeModelDefinitionRequest::builder().set_product_id("ABCDEFGHIJ".to_string()),
+            )
+            .send::<DescribeModelDefinitionResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        let mut template = resp.data_template().unwrap();
+        assert!(matches!(
+            template.property("power_switch").unwrap().define,
+            DataDefine::Bool { ref mapping } if mapping["1"] == "on"
+        ));
+        match template.property("brightness").unwrap().define {
+            DataDefine::Int(ref int) => {
+                assert_eq!((int.max.as_str(), int.unit.as_str()), ("100", "%"))
+            }
+            ref define => panic!("unexpected {:?}", define),
+        }
+        match template.property("schedule").unwrap().define {
+            DataDefine::Array { ref array_info } => match **array_info {
+                DataDefine::Struct { ref specs } => {
+                    assert_eq!(specs[0].define, DataDefine::Timestamp)
+                }
+                ref define => panic!("unexpected {:?}", define),
+            },
+            ref define => panic!("unexpected {:?}", define),
+        }
+        match template.property("location").unwrap().define {
+            DataDefine::Unknown(ref define) => assert_eq!(define["type"], "geopoint"),
+            ref define => panic!("unexpected {:?}", define),
+        }
+        assert_eq!(template.property("brightness").unwrap().extra["isUsed"], 1);
+        assert_eq!(template.events[0].event_type, "alert");
+        assert_eq!(template.actions[0].extra["timeout"], 5);
+        assert_eq!(template.extra["category"], "light");
+
+        if let DataDefine::Int(ref mut int) = template.properties[1].define {
+            int.max = "80".to_string();
+        }
+        iotexplorer
+            .modify_model_definition(
+                ModifyModelDefinitionRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_data_template(&template)
+                    .unwrap(),
+            )
+            .send::<ModifyModelDefinitionResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        let sent: serde_json::Value =
+            serde_json::from_str(&body(&transport, "ModifyModelDefinition")).unwrap();
+        let schema: serde_json::Value =
+            serde_json::from_str(sent["ModelSchema"].as_str().unwrap()).unwrap();
+        let mut expected: serde_json::Value = serde_json::from_str(TEMPLATE).unwrap();
+        expected["properties"][1]["define"]["max"] = "80".into();
+        assert_eq!(schema, expected);
+        let request = &transport.requests_for("ModifyModelDefinition")[0];
+        assert_eq!(request.url, "https://iotexplorer.tencentcloudapi.com/");
+        assert_eq!(request.headers["X-TC-Version"], "2019-04-23");
+    }
+
+    #[tokio::test]
+    async fn test_device_data() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "CallDeviceActionSync",
+                r#"{"Response":{"ClientToken":"token-1","OutputParams":"{\"done\":1}","Status":"OK","RequestId":"req-1"}}"#,
+            )
+            .respond(
+                "DescribeDeviceData",
+                r#"{"Response":{"Data":"{\"power_switch\":{\"Value\":1,\"LastUpdate\":1600000000000}}","RequestId":"req-2"}}"#,
+            )
+            .respond(
+                "DescribeDeviceDataHistory",
+                r#"{"Response":{"FieldName":"brightness","Listover":false,"Context":"ctx-1","Results":[{"Time":"1600000000000","Value":"10"}],"RequestId":"req-3"}}"#,
+            )
+            .respond(
+                "DescribeDeviceDataHistory",
+                r#"{"Response":{"FieldName":"brightness","Listover":true,"Context":"","Results":[{"Time":"1600000060000","Value":"20"}],"RequestId":"req-4"}}"#,
+            );
+        let iotexplorer = client(&transport).iotexplorer();
+
+        let resp = iotexplorer
+            .call_device_action_sync(
+                CallDeviceActionSyncRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_device_name("lamp-1".to_string())
+                    .set_action_id("blink".to_string())
+                    .set_input_json(&serde_json::json!({"times": 3}))
+                    .unwrap(),
+            )
+            .send::<CallDeviceActionSyncResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resp.output::<serde_json::Value>().unwrap()["done"], 1);
+        assert_eq!(
+            body(&transport, "CallDeviceActionSync"),
+            r#"{"ProductId":"ABCDEFGHIJ","DeviceName":"lamp-1","ActionId":"blink","InputParams":"{\"times\":3}"}"#
+        );
+
+        let resp = iotexplorer
+            .describe_device_data(
+                DescribeDeviceDataRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_device_name("lamp-1".to_string()),
+            )
+            .send::<DescribeDeviceDataResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        let properties = resp.properties().unwrap();
+        assert_eq!(properties["power_switch"].value, 1);
+
+        let values: Vec<_> = iotexplorer
+            .describe_device_data_history_stream(
+                DescribeDeviceDataHistoryRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_device_name("lamp-1".to_string())
+                    .set_field_name("brightness".to_string()),
+            )
+            .map(|item| item.unwrap().unwrap().value)
+            .collect()
+            .await;
+        assert_eq!(values, vec!["10", "20"]);
+    }
+}
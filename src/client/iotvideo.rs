@@ -0,0 +1,294 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::stream::BoxStream;
+
+use crate::{request::iotvideo::*, ResponseResult};
+
+use super::{paginate::paginate, Client, ServiceClient};
+
+pub struct IOTVideoClient {
+    client: Client,
+}
+
+impl IOTVideoClient {
+    pub fn new(client: Client) -> IOTVideoClient {
+        IOTVideoClient { client }
+    }
+}
+
+impl IOTVideoClient {
+    pub fn create_product(
+        &self,
+        req: CreateProductRequestBuilder,
+    ) -> ServiceClient<CreateProductRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_product(
+        &self,
+        req: DescribeProductRequestBuilder,
+    ) -> ServiceClient<DescribeProductRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_products(
+        &self,
+        req: DescribeProductsRequestBuilder,
+    ) -> ServiceClient<DescribeProductsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_product(
+        &self,
+        req: DeleteProductRequestBuilder,
+    ) -> ServiceClient<DeleteProductRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_device(
+        &self,
+        req: CreateDeviceRequestBuilder,
+    ) -> ServiceClient<CreateDeviceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_device(
+        &self,
+        req: DescribeDeviceRequestBuilder,
+    ) -> ServiceClient<DescribeDeviceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_devices(
+        &self,
+        req: DescribeDevicesRequestBuilder,
+    ) -> ServiceClient<DescribeDevicesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_device(
+        &self,
+        req: DeleteDeviceRequestBuilder,
+    ) -> ServiceClient<DeleteDeviceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_cloud_storage(
+        &self,
+        req: CreateCloudStorageRequestBuilder,
+    ) -> ServiceClient<CreateCloudStorageRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_cloud_storage(
+        &self,
+        req: DescribeCloudStorageRequestBuilder,
+    ) -> ServiceClient<DescribeCloudStorageRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_cloud_storage_date(
+        &self,
+        req: DescribeCloudStorageDateRequestBuilder,
+    ) -> ServiceClient<DescribeCloudStorageDateRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_cloud_storage_time(
+        &self,
+        req: DescribeCloudStorageTimeRequestBuilder,
+    ) -> ServiceClient<DescribeCloudStorageTimeRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_cloud_storage_events(
+        &self,
+        req: DescribeCloudStorageEventsRequestBuilder,
+    ) -> ServiceClient<DescribeCloudStorageEventsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn generate_signed_video_url(
+        &self,
+        req: GenerateSignedVideoURLRequestBuilder,
+    ) -> ServiceClient<GenerateSignedVideoURLRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn upload_firmware(
+        &self,
+        req: UploadFirmwareRequestBuilder,
+    ) -> ServiceClient<UploadFirmwareRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_firmware(
+        &self,
+        req: DescribeFirmwareRequestBuilder,
+    ) -> ServiceClient<DescribeFirmwareRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_firmware(
+        &self,
+        req: DeleteFirmwareRequestBuilder,
+    ) -> ServiceClient<DeleteFirmwareRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn publish_firmware_update_message(
+        &self,
+        req: PublishFirmwareUpdateMessageRequestBuilder,
+    ) -> ServiceClient<PublishFirmwareUpdateMessageRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    /// Signs `video_url` with `GenerateSignedVideoURL` so it can be played for
+    /// the next `valid_for`.
+    pub async fn signed_video_url(
+        &self,
+        video_url: &str,
+        valid_for: Duration,
+    ) -> ResponseResult<String> {
+        let expire_time = (SystemTime::now() + valid_for)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let req = GenerateSignedVideoURLRequest::builder()
+            .set_video_url(video_url.to_string())
+            .set_expire_time(expire_time as i64);
+        let resp = self
+            .generate_signed_video_url(req)
+            .send::<GenerateSignedVideoURLResponse>()
+            .await?;
+        Ok(resp.map(|resp| resp.into_inner().signed_video_url))
+    }
+
+    /// Every event matching `req`, following the `Context` cursor until
+    /// `Listover`.
+    pub fn describe_cloud_storage_events_stream(
+        &self,
+        req: DescribeCloudStorageEventsRequestBuilder,
+    ) -> BoxStream<'static, ResponseResult<CloudStorageEvent>> {
+        let client = self.client.clone();
+        paginate(
+            req,
+            move |req| {
+                ServiceClient::new(client.clone(), req).send::<DescribeCloudStorageEventsResponse>()
+            },
+            |req, resp| {
+                let next = if resp.list_over || resp.context.is_empty() {
+                    None
+                } else {
+                    Some(req.clone().set_context(resp.context))
+                };
+                (resp.events, next)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use futures::StreamExt;
+
+    use crate::{
+        request::iotvideo::*,
+        transport::{mock::client, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_signed_video_url() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "DescribeCloudStorageTime",
+                r#"{"Response":{"Data":{"TimeList":[{"StartTime":1600000000,"EndTime":1600000600}],"VideoURL":"https://video.example.com/ABCDEFGHIJ/cam-1.m3u8"},"RequestId":"req-1"}}"#,
+            )
+            .respond(
+                "GenerateSignedVideoURL",
+                r#"{"Response":{"SignedVideoURL":"https://video.example.com/ABCDEFGHIJ/cam-1.m3u8?t=5f5e1&us=abc&sign=0123abcd","RequestId":"req-2"}}"#,
+            );
+        let iotvideo = client(&transport).iotvideo();
+
+        let recordings = iotvideo
+            .describe_cloud_storage_time(
+                DescribeCloudStorageTimeRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_device_name("cam-1".to_string())
+                    .set_date("2020-09-13".to_string()),
+            )
+            .send::<DescribeCloudStorageTimeResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(recordings.data.time_list[0].end_time, 1600000600);
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let url = iotvideo
+            .signed_video_url(&recordings.data.video_url, Duration::from_secs(3600))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(url.ends_with("&sign=0123abcd"));
+
+        let request = &transport.requests_for("GenerateSignedVideoURL")[0];
+        assert_eq!(request.url, "https://iotvideo.tencentcloudapi.com/");
+        assert_eq!(request.headers["X-TC-Version"], "2021-11-25");
+        let body: serde_json::Value =
+            serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(
+            body["VideoURL"],
+            "https://video.example.com/ABCDEFGHIJ/cam-1.m3u8"
+        );
+        let expire_time = body["ExpireTime"].as_i64().unwrap();
+        assert!((now + 3600..=now + 3605).contains(&expire_time));
+    }
+
+    #[tokio::test]
+    async fn test_cloud_storage() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "DescribeCloudStorage",
+                r#"{"Response":{"Status":1,"Type":2,"ExpireTime":1700000000,"ShiftDuration":7,"UserId":"","RequestId":"req-1"}}"#,
+            )
+            .respond(
+                "DescribeCloudStorageEvents",
+                r#"{"Response":{"Events":[{"StartTime":1,"EndTime":2,"EventId":"motion"}],"Context":"ctx-1","Listover":false,"Total":2,"RequestId":"req-2"}}"#,
+            )
+            .respond(
+                "DescribeCloudStorageEvents",
+                r#"{"Response":{"Events":[{"StartTime":3,"EndTime":4,"EventId":"sound"}],"Context":"","Listover":true,"Total":2,"RequestId":"req-3"}}"#,
+            );
+        let iotvideo = client(&transport).iotvideo();
+
+        let storage = iotvideo
+            .describe_cloud_storage(
+                DescribeCloudStorageRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_device_name("cam-1".to_string()),
+            )
+            .send::<DescribeCloudStorageResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((storage.status, storage.shift_duration), (1, 7));
+
+        let events: Vec<_> = iotvideo
+            .describe_cloud_storage_events_stream(
+                DescribeCloudStorageEventsRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_device_name("cam-1".to_string()),
+            )
+            .map(|event| event.unwrap().unwrap().event_id)
+            .collect()
+            .await;
+        assert_eq!(events, vec!["motion", "sound"]);
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// What `ServiceClient::send` observed for a single call.
+#[derive(Debug, Clone)]
+pub struct RequestMetric<'a> {
+    pub service: &'a str,
+    pub action: &'a str,
+    pub region: &'a str,
+    pub latency: Duration,
+    /// `None` when no response was received.
+    pub status: Option<u16>,
+    /// Attempts made after the first one, see `RetryPolicy`.
+    pub retries: u32,
+    /// The api error code, or `TransportError`/`BodyFormatError` for failures
+    /// on the client side.
+    pub error_code: Option<&'a str>,
+}
+
+pub trait MetricsRecorder: Send + Sync + Debug {
+    fn record(&self, metric: &RequestMetric<'_>);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetricKey {
+    pub service: String,
+    pub action: String,
+    pub region: String,
+}
+
+impl<'a> From<&RequestMetric<'a>> for MetricKey {
+    fn from(metric: &RequestMetric<'a>) -> Self {
+        MetricKey {
+            service: metric.service.to_string(),
+            action: metric.action.to_string(),
+            region: metric.region.to_string(),
+        }
+    }
+}
+
+/// Upper bounds of the latency histogram buckets, in milliseconds.
+pub const LATENCY_BUCKETS: &[u64] = &[5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Histogram {
+    /// `counts[i]` is the number of samples `<= LATENCY_BUCKETS[i]`, the last
+    /// entry counts the samples above every bound.
+    pub counts: Vec<u64>,
+    pub count: u64,
+    pub sum: Duration,
+}
+
+impl Histogram {
+    fn observe(&mut self, latency: Duration) {
+        if self.counts.is_empty() {
+            self.counts = vec![0; LATENCY_BUCKETS.len() + 1];
+        }
+        let millis = latency.as_millis();
+        let index = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| millis <= *bound as u128)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.counts[index] += 1;
+        self.count += 1;
+        self.sum += latency;
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    pub requests: HashMap<MetricKey, u64>,
+    pub errors: HashMap<(MetricKey, String), u64>,
+    pub retries: HashMap<MetricKey, u64>,
+    pub latencies: HashMap<MetricKey, Histogram>,
+}
+
+impl MetricsSnapshot {
+    pub fn requests(&self, service: &str, action: &str) -> u64 {
+        self.requests
+            .iter()
+            .filter(|(key, _)| key.service == service && key.action == action)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn retries(&self, service: &str, action: &str) -> u64 {
+        self.retries
+            .iter()
+            .filter(|(key, _)| key.service == service && key.action == action)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn errors(&self, service: &str, action: &str, code: &str) -> u64 {
+        self.errors
+            .iter()
+            .filter(|((key, c), _)| key.service == service && key.action == action && c == code)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+/// Keeps every metric in memory, no collector needed.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMetrics {
+    snapshot: Arc<Mutex<MetricsSnapshot>>,
+}
+
+impl InMemoryMetrics {
+    pub fn new() -> InMemoryMetrics {
+        InMemoryMetrics::default()
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.snapshot.lock().unwrap().clone()
+    }
+}
+
+impl MetricsRecorder for InMemoryMetrics {
+    fn record(&self, metric: &RequestMetric<'_>) {
+        let key = MetricKey::from(metric);
+        let mut snapshot = self.snapshot.lock().unwrap();
+        *snapshot.requests.entry(key.clone()).or_default() += 1;
+        *snapshot.retries.entry(key.clone()).or_default() += metric.retries as u64;
+        snapshot
+            .latencies
+            .entry(key.clone())
+            .or_default()
+            .observe(metric.latency);
+        if let Some(code) = metric.error_code {
+            *snapshot.errors.entry((key, code.to_string())).or_default() += 1;
+        }
+    }
+}
+
+/// Forwards to the global recorder of the `metrics` crate.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Default)]
+pub struct MetricsCrateRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn record(&self, metric: &RequestMetric<'_>) {
+        let labels = [
+            ("service", metric.service.to_string()),
+            ("action", metric.action.to_string()),
+            ("region", metric.region.to_string()),
+        ];
+        metrics::counter!("tencentcloud_requests_total", &labels).increment(1);
+        metrics::histogram!("tencentcloud_request_duration_seconds", &labels)
+            .record(metric.latency.as_secs_f64());
+        metrics::counter!("tencentcloud_retries_total", &labels).increment(metric.retries as u64);
+        if let Some(code) = metric.error_code {
+            let [service, action, region] = labels;
+            metrics::counter!(
+                "tencentcloud_errors_total",
+                &[service, action, region, ("code", code.to_string())]
+            )
+            .increment(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Histogram, LATENCY_BUCKETS};
+
+    #[test]
+    fn test_histogram() {
+        let mut histogram = Histogram::default();
+        histogram.observe(Duration::from_millis(3));
+        histogram.observe(Duration::from_millis(5));
+        histogram.observe(Duration::from_millis(300));
+        histogram.observe(Duration::from_secs(60));
+        assert_eq!(histogram.count, 4);
+        assert_eq!(histogram.counts[0], 2);
+        assert_eq!(histogram.counts[6], 1);
+        assert_eq!(histogram.counts[LATENCY_BUCKETS.len()], 1);
+        assert_eq!(histogram.sum, Duration::from_millis(60308));
+    }
+}
@@ -1,18 +1,41 @@
-use std::{fmt::Debug, sync::Arc, time::Duration};
+use std::{convert::TryFrom, fmt::Debug, sync::Arc, time::Instant};
 
 use crate::{
     credential::Credential,
     profile::{ClientProfile, HTTProfile, Profile},
     region::Region,
     request::{RequestBuilder, ServiceRequest},
-    Flat, IntoRequest, ROOT_DOMAIN,
+    response::{ErrorResponse, Response},
+    transport::{HttpRequest, HttpTransport},
+    Flat, IntoRequest, ResponseError, ROOT_DOMAIN,
 };
 
+pub mod batch;
+pub mod clb;
+mod config;
+pub mod cvm;
+mod fan_out;
 pub mod iotcloud;
+pub mod iotexplorer;
+pub mod iotvideo;
+pub mod metrics;
+pub(crate) mod paginate;
+pub mod rate_limit;
+pub mod retry;
+#[cfg(feature = "tracing")]
+mod trace;
+pub mod vpc;
+
+pub use self::metrics::{InMemoryMetrics, MetricsRecorder, RequestMetric};
+pub use rate_limit::RateLimiter;
+pub use retry::RetryPolicy;
 
 #[derive(Clone)]
 pub struct Client {
-    client: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
+    limiter: Option<RateLimiter>,
+    retry: Option<RetryPolicy>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
     config: Configuration,
 }
 
@@ -35,6 +58,10 @@ pub struct ClientBuilder {
     client_profile: ClientProfile,
     http_profile: HTTProfile,
     credential: Credential,
+    transport: Option<Arc<dyn HttpTransport>>,
+    limiter: Option<RateLimiter>,
+    retry: Option<RetryPolicy>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
 }
 
 impl ClientBuilder {
@@ -58,16 +85,40 @@ impl ClientBuilder {
         self
     }
 
+    /// Sends requests through `transport` instead of the default reqwest client.
+    pub fn transport<HT: HttpTransport + 'static>(mut self, transport: HT) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Throttles every request through `limiter` before it is sent.
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Sends failed requests again as `policy` says. Nothing is retried
+    /// without one, and COS requests never are.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Reports every request to `recorder`.
+    pub fn metrics<MR: MetricsRecorder + 'static>(mut self, recorder: MR) -> Self {
+        self.metrics = Some(Arc::new(recorder));
+        self
+    }
+
     pub fn build(mut self) -> crate::Result<Client> {
+        config::check_profile(&self.client_profile, &self.http_profile)?;
         if self.http_profile.root_domain.is_empty() {
             self.http_profile.root_domain = ROOT_DOMAIN.to_string();
         }
-        // TODO: handle error
-        let client = reqwest::Client::builder()
-            .connect_timeout(Duration::from_secs(self.http_profile.timeout))
-            .pool_idle_timeout(Duration::from_secs(3600))
-            .pool_max_idle_per_host(100)
-            .build()?;
+        let transport = match self.transport.take() {
+            Some(transport) => transport,
+            None => default_transport(&self.http_profile)?,
+        };
 
         let config = Configuration {
             region: self.region,
@@ -75,54 +126,392 @@ impl ClientBuilder {
             credential: Arc::new(self.credential),
         };
 
-        Ok(Client { client, config })
+        Ok(Client {
+            transport,
+            limiter: self.limiter,
+            retry: self.retry,
+            metrics: self.metrics,
+            config,
+        })
     }
 }
 
+#[cfg(feature = "reqwest")]
+fn default_transport(http_profile: &HTTProfile) -> crate::Result<Arc<dyn HttpTransport>> {
+    Ok(Arc::new(crate::transport::ReqwestTransport::new(
+        http_profile,
+    )?))
+}
+
+#[cfg(not(feature = "reqwest"))]
+fn default_transport(_: &HTTProfile) -> crate::Result<Arc<dyn HttpTransport>> {
+    Err(crate::Error::MissingTransport)
+}
+
 pub struct ServiceClient<T> {
-    client: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
+    limiter: Option<RateLimiter>,
+    retry: Option<RetryPolicy>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
     request: RequestBuilder<T>,
 }
 
 impl<T> ServiceClient<T> {
     pub fn new<IR: IntoRequest<Request = T>>(client: Client, ir: IR) -> ServiceClient<T> {
         let request = ir.into_request(client.config);
-        let client = client.client;
-        ServiceClient { client, request }
+        ServiceClient {
+            transport: client.transport,
+            limiter: client.limiter,
+            retry: client.retry,
+            metrics: client.metrics,
+            request,
+        }
     }
 }
 
 impl Client {
+    pub fn clb(&self) -> clb::CLBClient {
+        clb::CLBClient::new(self.clone())
+    }
+
+    /// A client of the COS bucket `bucket`, e.g. `examplebucket-1250000000`.
+    pub fn cos(&self, bucket: &str) -> crate::Result<crate::cos::CosClient> {
+        crate::cos::CosClient::new(
+            self.transport.clone(),
+            self.limiter.clone(),
+            self.metrics.clone(),
+            self.config.clone(),
+            bucket,
+        )
+    }
+
+    pub fn cvm(&self) -> cvm::CVMClient {
+        cvm::CVMClient::new(self.clone())
+    }
+
     pub fn iotcloud(&self) -> iotcloud::IOTClient {
         iotcloud::IOTClient::new(self.clone())
     }
+
+    pub fn iotexplorer(&self) -> iotexplorer::IOTExplorerClient {
+        iotexplorer::IOTExplorerClient::new(self.clone())
+    }
+
+    pub fn iotvideo(&self) -> iotvideo::IOTVideoClient {
+        iotvideo::IOTVideoClient::new(self.clone())
+    }
+
+    pub fn vpc(&self) -> vpc::VPCClient {
+        vpc::VPCClient::new(self.clone())
+    }
 }
 
 impl<T> ServiceClient<T>
 where
     T: Flat + ServiceRequest + Flat + Debug + serde::Serialize,
 {
-    pub async fn send<R: serde::de::DeserializeOwned>(
-        self,
-    ) -> crate::ResponseResult<crate::response::Response<R>> {
-        let req: reqwest::Request = self.request.into();
-        let client = self.client;
-        let response = client.execute(req).await?;
-        match response.json().await {
-            Ok(r) => Ok(Ok(r)),
-            Err(e) => Ok(Err(e.into())),
+    pub async fn send<R: serde::de::DeserializeOwned>(self) -> crate::ResponseResult<Response<R>> {
+        let service = self.request.inner.service();
+        let action = self.request.inner.action();
+        let region = self.request.region;
+        let start = Instant::now();
+        let execution = execute(
+            &*self.transport,
+            self.limiter.as_ref(),
+            self.retry.as_ref(),
+            self.request,
+        );
+        #[cfg(feature = "tracing")]
+        let execution = tracing::Instrument::instrument(
+            execution,
+            trace::request_span(service, action, region),
+        );
+        let (status, retries, result) = execution.await;
+        if let Some(ref metrics) = self.metrics {
+            let error_code = match result {
+                Ok(Ok(_)) => None,
+                Ok(Err(ref e)) => Some(e.code().unwrap_or("BodyFormatError")),
+                Err(_) => Some("TransportError"),
+            };
+            metrics.record(&RequestMetric {
+                service,
+                action,
+                region: region.as_ref(),
+                latency: start.elapsed(),
+                status,
+                retries,
+                error_code,
+            });
+        }
+        result
+    }
+}
+
+/// Sends `request` until it succeeds or `retry` gives up, returning the
+/// status of the last response and the number of retries along with it.
+async fn execute<T, R>(
+    transport: &dyn HttpTransport,
+    limiter: Option<&RateLimiter>,
+    retry: Option<&RetryPolicy>,
+    request: RequestBuilder<T>,
+) -> (Option<u16>, u32, crate::ResponseResult<Response<R>>)
+where
+    T: Flat + ServiceRequest + Debug + serde::Serialize,
+    R: serde::de::DeserializeOwned,
+{
+    let service = request.inner.service();
+    let action = request.inner.action();
+    let request = match HttpRequest::try_from(request) {
+        Ok(request) => request,
+        Err(e) => return (None, 0, Err(e)),
+    };
+    let mut retries = 0;
+    loop {
+        if let Some(limiter) = limiter {
+            let _queued = limiter.acquire(service, action).await;
+            #[cfg(feature = "tracing")]
+            trace::record_queued(_queued);
+        }
+        let req = request.clone();
+        #[cfg(feature = "tracing")]
+        let req = trace::record_request(req, retries);
+        let (status, result) = attempt(transport, req).await;
+        match retry.and_then(|policy| policy.delay(retries, &result)) {
+            Some(delay) => {
+                retries += 1;
+                #[cfg(feature = "tracing")]
+                trace::record_retry(retries, delay);
+                tokio::time::sleep(delay).await;
+            }
+            None => return (status, retries, result),
         }
     }
 }
 
+async fn attempt<R: serde::de::DeserializeOwned>(
+    transport: &dyn HttpTransport,
+    request: HttpRequest,
+) -> (Option<u16>, crate::ResponseResult<Response<R>>) {
+    let response = match transport.send(request).await {
+        Ok(response) => response,
+        Err(e) => {
+            let result = Err(e);
+            #[cfg(feature = "tracing")]
+            trace::record_error(&result);
+            return (None, result);
+        }
+    };
+    let result = decode(&response.body);
+    #[cfg(feature = "tracing")]
+    trace::record_response(response.status, &result);
+    (Some(response.status), Ok(result))
+}
+
+/// Parses `body` once, then decodes it as an `ErrorResponse` when it has an
+/// `Error` member and as `R` otherwise.
+fn decode<R: serde::de::DeserializeOwned>(body: &[u8]) -> Result<Response<R>, ResponseError> {
+    let response = serde_json::from_slice::<Response<serde_json::Value>>(body)?.into_inner();
+    if response.get("Error").is_some() {
+        let error: ErrorResponse = serde_json::from_value(response)?;
+        return Err(error.into());
+    }
+    let request_id = response
+        .get("RequestId")
+        .and_then(|id| id.as_str())
+        .map(str::to_string);
+    Ok(Response::new(serde_json::from_value(response)?, request_id))
+}
+
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use crate::{
-        BatchUpdateFirmwareRequest, BatchUpdateFirmwareResponse, ClientProfile, Credential,
-        DescribeProductsRequest, DescribeProductsResponse, HTTProfile, Region,
+        transport::MockTransport, BatchUpdateFirmwareRequest, BatchUpdateFirmwareResponse,
+        ClientProfile, Credential, DescribeProductsRequest, DescribeProductsResponse, HTTProfile,
+        Region,
     };
 
-    use super::Client;
+    use super::{Client, InMemoryMetrics, RetryPolicy};
+
+    #[tokio::test]
+    async fn test_mock_transport() {
+        let transport = MockTransport::new();
+        transport.respond(
+            "DescribeProducts",
+            r#"{"Response":{"RequestId":"req-1","TotalCount":0,"Products":[]}}"#,
+        );
+        let client = Client::builder()
+            .region(Region::APGuangzhou1)
+            .credential(
+                Credential::builder()
+                    .access_key("access_key")
+                    .secret_key("secret_key")
+                    .build(),
+            )
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let resp = client
+            .iotcloud()
+            .describe_products(DescribeProductsRequest::builder().set_limit(Some(10)))
+            .send::<DescribeProductsResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resp.request_id, "req-1");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "https://iotcloud.tencentcloudapi.com/");
+        assert_eq!(requests[0].headers["X-TC-Action"], "DescribeProducts");
+        assert_eq!(requests[0].headers["X-TC-Region"], "ap-guangzhou-1");
+        // the region header is sent but not signed
+        assert!(requests[0].headers["Authorization"]
+            .to_str()
+            .unwrap()
+            .contains("SignedHeaders=content-type;host,"));
+        assert!(requests[0].headers["Authorization"]
+            .to_str()
+            .unwrap()
+            .starts_with("TC3-HMAC-SHA256 Credential=access_key/"));
+        assert_eq!(requests[0].body.as_deref(), Some(&br#"{"Limit":10}"#[..]));
+    }
+
+    #[test]
+    fn test_decode() {
+        let resp = super::decode::<DescribeProductsResponse>(
+            br#"{"Response":{"RequestId":"req-1","TotalCount":0,"Products":[]}}"#,
+        )
+        .unwrap();
+        assert_eq!(resp.request_id(), Some("req-1"));
+
+        let err = super::decode::<DescribeProductsResponse>(
+            br#"{"Response":{"Error":{"Code":"InternalError","Message":"oops"},"RequestId":"req-2"}}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), Some("InternalError"));
+        assert_eq!(err.request_id(), Some("req-2"));
+
+        let err = super::decode::<DescribeProductsResponse>(b"<html>").unwrap_err();
+        assert!(matches!(err, crate::ResponseError::BodyFormat(_)));
+    }
+
+    #[tokio::test]
+    async fn test_metrics() {
+        let transport = MockTransport::new();
+        transport.respond(
+            "DescribeProducts",
+            r#"{"Response":{"RequestId":"req-1","TotalCount":0,"Products":[]}}"#,
+        );
+        transport.respond(
+            "BatchUpdateFirmware",
+            r#"{"Response":{"Error":{"Code":"RequestLimitExceeded","Message":"slow down"},"RequestId":"req-2"}}"#,
+        );
+        let metrics = InMemoryMetrics::new();
+        let client = Client::builder()
+            .region(Region::APGuangzhou1)
+            .transport(transport)
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            client
+                .iotcloud()
+                .describe_products(DescribeProductsRequest::builder())
+                .send::<DescribeProductsResponse>()
+                .await
+                .unwrap()
+                .unwrap();
+        }
+        let err = client
+            .iotcloud()
+            .batch_update_firmware(
+                BatchUpdateFirmwareRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_firmware_version("1.0.1".to_string()),
+            )
+            .send::<BatchUpdateFirmwareResponse>()
+            .await
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("RequestLimitExceeded"));
+        assert_eq!(err.request_id(), Some("req-2"));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.requests("iotcloud", "DescribeProducts"), 2);
+        assert_eq!(snapshot.requests("iotcloud", "BatchUpdateFirmware"), 1);
+        assert_eq!(
+            snapshot.errors("iotcloud", "BatchUpdateFirmware", "RequestLimitExceeded"),
+            1
+        );
+        assert_eq!(
+            snapshot.errors("iotcloud", "DescribeProducts", "RequestLimitExceeded"),
+            0
+        );
+        let latencies: u64 = snapshot.latencies.values().map(|h| h.count).sum();
+        assert_eq!(latencies, 3);
+        assert!(snapshot
+            .requests
+            .keys()
+            .all(|key| key.region == "ap-guangzhou-1"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry() {
+        let throttled = r#"{"Response":{"Error":{"Code":"RequestLimitExceeded","Message":"slow down"},"RequestId":"req-1"}}"#;
+        let transport = MockTransport::new();
+        transport
+            .respond("DescribeProducts", throttled)
+            .respond("DescribeProducts", throttled)
+            .respond(
+                "DescribeProducts",
+                r#"{"Response":{"RequestId":"req-2","TotalCount":0,"Products":[]}}"#,
+            );
+        let metrics = InMemoryMetrics::new();
+        let client = Client::builder()
+            .region(Region::APGuangzhou1)
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::new(2).base_delay(Duration::from_secs(1)))
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        let start = tokio::time::Instant::now();
+        let resp = client
+            .iotcloud()
+            .describe_products(DescribeProductsRequest::builder())
+            .send::<DescribeProductsResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resp.request_id, "req-2");
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+        assert_eq!(transport.requests().len(), 3);
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.requests("iotcloud", "DescribeProducts"), 1);
+        assert_eq!(snapshot.retries("iotcloud", "DescribeProducts"), 2);
+
+        // the last response is repeated, so a single retry is not enough
+        let transport = MockTransport::new();
+        transport.respond("DescribeProducts", throttled);
+        let client = Client::builder()
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::new(1))
+            .build()
+            .unwrap();
+        let err = client
+            .iotcloud()
+            .describe_products(DescribeProductsRequest::builder())
+            .send::<DescribeProductsResponse>()
+            .await
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("RequestLimitExceeded"));
+        assert_eq!(transport.requests().len(), 2);
+    }
 
     #[tokio::test]
     async fn test_batch_update_firmware() {
@@ -133,7 +522,9 @@ mod test {
             .build()
             .unwrap();
 
-        let req = BatchUpdateFirmwareRequest::builder().set_product_id("product_id".to_string());
+        let req = BatchUpdateFirmwareRequest::builder()
+            .set_product_id("product_id".to_string())
+            .set_firmware_version("1.0.1".to_string());
 
         client
             .iotcloud()
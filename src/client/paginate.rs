@@ -0,0 +1,51 @@
+use std::future::Future;
+
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+
+use crate::{response::Response, ResponseResult};
+
+/// Streams the items of a listing one page at a time. `fetch` sends a page
+/// request, `page` splits its response into the items and the request for
+/// the next page, `None` once the listing is over. The stream ends after the
+/// first error, which it yields.
+pub(crate) fn paginate<Req, R, T, F, Fut, P>(
+    first: Req,
+    fetch: F,
+    page: P,
+) -> BoxStream<'static, ResponseResult<T>>
+where
+    Req: Clone + Send + 'static,
+    R: Send + 'static,
+    T: Send + 'static,
+    F: FnMut(Req) -> Fut + Send + 'static,
+    Fut: Future<Output = ResponseResult<Response<R>>> + Send,
+    P: FnMut(&Req, R) -> (Vec<T>, Option<Req>) + Send + 'static,
+{
+    stream::unfold(
+        (Some(first), fetch, page),
+        |(req, mut fetch, mut page)| async move {
+            let req = req?;
+            let (items, next) = match fetch(req.clone()).await {
+                Ok(Ok(response)) => {
+                    let (items, next) = page(&req, response.into_inner());
+                    (Ok(Ok(items)), next)
+                }
+                Ok(Err(e)) => (Ok(Err(e)), None),
+                Err(e) => (Err(e), None),
+            };
+            Some((items, (next, fetch, page)))
+        },
+    )
+    .flat_map(|items| {
+        let items: Vec<ResponseResult<T>> = match items {
+            Ok(Ok(items)) => items.into_iter().map(|item| Ok(Ok(item))).collect(),
+            Ok(Err(e)) => vec![Ok(Err(e))],
+            Err(e) => vec![Err(e)],
+        };
+        stream::iter(items)
+    })
+    .boxed()
+}
@@ -0,0 +1,247 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+/// Requests per second the api allows by default, per service.
+///
+/// Tencent Cloud documents a frequency limit of 20 requests per second for
+/// the IoT Hub actions. Accounts with a raised quota, and the other services,
+/// have to be configured with `RateLimiterBuilder::service` or `action`.
+pub const DEFAULT_SERVICE_RATES: &[(&str, u32)] = &[("iotcloud", 20)];
+
+/// Client-side token buckets keyed by `(service, action)`.
+///
+/// Every action gets its own bucket. The rate of a bucket is looked up from
+/// the action override, then the service override, then the default. Cloning
+/// a `RateLimiter` (or a `Client` holding one) shares the buckets, so a whole
+/// process can stay within a single budget.
+///
+/// Nothing is limited unless configured: start from
+/// `RateLimiter::builder().api_defaults()` to get `DEFAULT_SERVICE_RATES`.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    default: Option<u32>,
+    services: HashMap<String, u32>,
+    actions: HashMap<(String, String), u32>,
+    buckets: Mutex<HashMap<(String, String), Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    rate: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(rate: u32) -> Bucket {
+        Bucket {
+            rate: rate as f64,
+            tokens: rate as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    /// Takes a token and returns how long the caller has to wait for it.
+    /// The token may be borrowed from the future, which keeps waiters in order.
+    fn take(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.updated = now;
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn builder() -> RateLimiterBuilder {
+        RateLimiterBuilder::default()
+    }
+
+    /// The requests per second allowed for `action` of `service`, if limited.
+    pub fn rate(&self, service: &str, action: &str) -> Option<u32> {
+        let inner = &self.inner;
+        inner
+            .actions
+            .get(&(service.to_string(), action.to_string()))
+            .or_else(|| inner.services.get(service))
+            .or_else(|| inner.default.as_ref())
+            .copied()
+    }
+
+    /// Waits until a request to `action` of `service` may be sent, and
+    /// returns how long that was.
+    ///
+    /// Dropping the future before it completes gives the token back.
+    pub async fn acquire(&self, service: &str, action: &str) -> Duration {
+        let rate = match self.rate(service, action) {
+            Some(rate) if rate > 0 => rate,
+            _ => return Duration::from_secs(0),
+        };
+        let key = (service.to_string(), action.to_string());
+        let wait = {
+            let mut buckets = self.inner.buckets.lock().unwrap();
+            buckets
+                .entry(key.clone())
+                .or_insert_with(|| Bucket::new(rate))
+                .take()
+        };
+        if wait > Duration::from_secs(0) {
+            let mut reservation = Reservation {
+                inner: &self.inner,
+                key,
+                used: false,
+            };
+            tokio::time::sleep(wait).await;
+            reservation.used = true;
+        }
+        wait
+    }
+}
+
+/// A token taken ahead of time, returned to its bucket if the wait is cancelled.
+struct Reservation<'a> {
+    inner: &'a Inner,
+    key: (String, String),
+    used: bool,
+}
+
+impl<'a> Drop for Reservation<'a> {
+    fn drop(&mut self) {
+        if self.used {
+            return;
+        }
+        let mut buckets = self.inner.buckets.lock().unwrap();
+        if let Some(bucket) = buckets.get_mut(&self.key) {
+            bucket.tokens = (bucket.tokens + 1.0).min(bucket.rate);
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RateLimiterBuilder {
+    default: Option<u32>,
+    services: HashMap<String, u32>,
+    actions: HashMap<(String, String), u32>,
+}
+
+impl RateLimiterBuilder {
+    /// Requests per second for every action without an override.
+    pub fn default_rate(mut self, rate: u32) -> Self {
+        self.default = Some(rate);
+        self
+    }
+
+    /// Requests per second for each action of `service`.
+    pub fn service(mut self, service: &str, rate: u32) -> Self {
+        self.services.insert(service.to_string(), rate);
+        self
+    }
+
+    /// Limits the services of `DEFAULT_SERVICE_RATES` that were not
+    /// configured yet.
+    pub fn api_defaults(mut self) -> Self {
+        for (service, rate) in DEFAULT_SERVICE_RATES {
+            self.services.entry(service.to_string()).or_insert(*rate);
+        }
+        self
+    }
+
+    pub fn action(mut self, service: &str, action: &str, rate: u32) -> Self {
+        self.actions
+            .insert((service.to_string(), action.to_string()), rate);
+        self
+    }
+
+    pub fn build(self) -> RateLimiter {
+        RateLimiter {
+            inner: Arc::new(Inner {
+                default: self.default,
+                services: self.services,
+                actions: self.actions,
+                buckets: Default::default(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::RateLimiter;
+
+    #[test]
+    fn test_rate() {
+        let limiter = RateLimiter::builder()
+            .default_rate(20)
+            .service("iotcloud", 10)
+            .action("iotcloud", "DescribeProducts", 5)
+            .build();
+        assert_eq!(limiter.rate("iotcloud", "DescribeProducts"), Some(5));
+        assert_eq!(limiter.rate("iotcloud", "DescribeDevice"), Some(10));
+        assert_eq!(limiter.rate("cvm", "DescribeInstances"), Some(20));
+        assert_eq!(
+            RateLimiter::builder().build().rate("cvm", "RunInstances"),
+            None
+        );
+
+        let limiter = RateLimiter::builder()
+            .service("iotcloud", 50)
+            .api_defaults()
+            .build();
+        assert_eq!(limiter.rate("iotcloud", "PublishMessage"), Some(50));
+        let limiter = RateLimiter::builder().api_defaults().build();
+        assert_eq!(limiter.rate("iotcloud", "DescribeDevice"), Some(20));
+        assert_eq!(limiter.rate("cvm", "RunInstances"), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire() {
+        let limiter = RateLimiter::builder().default_rate(2).build();
+        let shared = limiter.clone();
+        let start = Instant::now();
+        for _ in 0..5 {
+            shared.acquire("iotcloud", "DescribeProducts").await;
+        }
+        // two from the initial burst, then one every half second
+        assert_eq!(start.elapsed(), Duration::from_millis(1500));
+
+        // other actions have their own bucket
+        let start = Instant::now();
+        limiter.acquire("iotcloud", "DescribeDevice").await;
+        assert_eq!(start.elapsed(), Duration::from_secs(0));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancelled_acquire() {
+        let limiter = RateLimiter::builder().default_rate(1).build();
+        let start = Instant::now();
+        limiter.acquire("iotcloud", "DescribeProducts").await;
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(100),
+            limiter.acquire("iotcloud", "DescribeProducts"),
+        )
+        .await;
+        assert!(cancelled.is_err());
+        // the cancelled caller's token is back, so this one only waits a second
+        limiter.acquire("iotcloud", "DescribeProducts").await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+}
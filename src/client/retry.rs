@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use crate::{Error, ResponseResult};
+
+/// Which failed calls `ServiceClient::send` makes again, and how long it
+/// waits before each new attempt.
+///
+/// Transport failures are retried, as are api errors whose code is one of
+/// `codes` or a sub-code of one, e.g. `RequestLimitExceeded.UinLimitExceeded`.
+/// The wait doubles with every attempt, from `base_delay` up to `max_delay`.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    codes: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            codes: vec![
+                "InternalError".to_string(),
+                "RequestLimitExceeded".to_string(),
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            ..Default::default()
+        }
+    }
+
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Also retries the api errors with `code`.
+    pub fn retry_on<S: Into<String>>(mut self, code: S) -> Self {
+        self.codes.push(code.into());
+        self
+    }
+
+    /// How long to wait before retrying a call that ended with `result`
+    /// after `retries` retries, or `None` to give up.
+    pub fn delay<R>(&self, retries: u32, result: &ResponseResult<R>) -> Option<Duration> {
+        if retries >= self.max_retries || !self.is_retryable(result) {
+            return None;
+        }
+        let delay = self
+            .base_delay
+            .checked_mul(1 << retries.min(16))
+            .unwrap_or(self.max_delay);
+        Some(delay.min(self.max_delay))
+    }
+
+    fn is_retryable<R>(&self, result: &ResponseResult<R>) -> bool {
+        match result {
+            Ok(Ok(_)) => false,
+            Ok(Err(e)) => match e.code() {
+                Some(code) => self.codes.iter().any(|retryable| {
+                    code == retryable
+                        || (code.starts_with(retryable.as_str())
+                            && code[retryable.len()..].starts_with('.'))
+                }),
+                None => false,
+            },
+            #[cfg(feature = "reqwest")]
+            Err(Error::HTTP(_)) => true,
+            Err(Error::Transport(_)) => true,
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::RetryPolicy;
+    use crate::{Error, ResponseError, ResponseResult};
+
+    fn api_error(code: &str) -> ResponseResult<()> {
+        Ok(Err(ResponseError::Api {
+            code: code.to_string(),
+            message: String::new(),
+            request_id: String::new(),
+        }))
+    }
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::new(4)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(3))
+            .retry_on("ResourceInUse");
+        let throttled = api_error("RequestLimitExceeded.UinLimitExceeded");
+        assert_eq!(policy.delay(0, &throttled), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(1, &throttled), Some(Duration::from_secs(2)));
+        assert_eq!(policy.delay(2, &throttled), Some(Duration::from_secs(3)));
+        assert_eq!(policy.delay(4, &throttled), None);
+
+        assert!(policy.delay(0, &api_error("ResourceInUse")).is_some());
+        assert!(policy
+            .delay(0, &api_error("RequestLimitExceededX"))
+            .is_none());
+        assert!(policy.delay(0, &api_error("InvalidParameter")).is_none());
+        assert!(policy.delay(0, &Ok(Ok(()))).is_none());
+        let transport: ResponseResult<()> = Err(Error::Transport("reset".into()));
+        assert!(policy.delay(0, &transport).is_some());
+        let invalid: ResponseResult<()> = Err(Error::InvalidRequest("bad".to_string()));
+        assert!(policy.delay(0, &invalid).is_none());
+    }
+}
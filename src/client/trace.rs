@@ -0,0 +1,326 @@
+use std::time::Duration;
+
+use tracing::{field::Empty, Span};
+
+use crate::{response::Response, transport::HttpRequest, Region, ResponseError, ResponseResult};
+
+/// One span per `ServiceClient::send`, reported to OpenTelemetry as
+/// `service.action` through the `otel.name` field.
+///
+/// The span covers the wait for the rate limiter and every attempt, each of
+/// which is an event of the span.
+pub fn request_span(service: &str, action: &str, region: Region) -> Span {
+    tracing::info_span!(
+        "tencentcloud.request",
+        otel.name = %format_args!("{}.{}", service, action),
+        otel.kind = "client",
+        service,
+        action,
+        region = region.as_ref(),
+        endpoint = Empty,
+        http.status_code = Empty,
+        request_id = Empty,
+        error.code = Empty,
+    )
+}
+
+pub fn record_queued(wait: Duration) {
+    if wait > Duration::from_secs(0) {
+        tracing::debug!(
+            wait_ms = wait.as_millis() as u64,
+            "waited for the rate limiter"
+        );
+    }
+}
+
+/// Records attempt `attempt`, from 0, and adds the trace context to `request`
+/// with the `otel` feature.
+pub fn record_request(request: HttpRequest, attempt: u32) -> HttpRequest {
+    let span = Span::current();
+    if let Some(host) = request.headers.get("Host").and_then(|h| h.to_str().ok()) {
+        span.record("endpoint", host);
+    }
+    tracing::debug!(attempt, method = %request.method, url = %request.url, "sending request");
+    #[cfg(feature = "otel")]
+    let request = inject_context(request);
+    request
+}
+
+pub fn record_retry(attempt: u32, delay: Duration) {
+    tracing::info!(
+        attempt,
+        delay_ms = delay.as_millis() as u64,
+        "retrying request"
+    );
+}
+
+/// Adds the context of the current span to `request`, as the `traceparent`
+/// header with the W3C propagator, so the api call joins the caller's trace.
+/// The propagator is the one installed with
+/// `opentelemetry::global::set_text_map_propagator`.
+#[cfg(feature = "otel")]
+fn inject_context(mut request: HttpRequest) -> HttpRequest {
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    struct Injector<'a>(&'a mut http::HeaderMap);
+
+    impl<'a> opentelemetry::propagation::Injector for Injector<'a> {
+        fn set(&mut self, key: &str, value: String) {
+            let name = http::header::HeaderName::from_bytes(key.as_bytes());
+            let value = http::HeaderValue::from_str(&value);
+            if let (Ok(name), Ok(value)) = (name, value) {
+                self.0.insert(name, value);
+            }
+        }
+    }
+
+    let context = Span::current().context();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut Injector(&mut request.headers))
+    });
+    request
+}
+
+pub fn record_response<R>(status: u16, result: &Result<Response<R>, ResponseError>) {
+    let span = Span::current();
+    span.record("http.status_code", status);
+    let request_id = match result {
+        Ok(resp) => resp.request_id(),
+        Err(e) => e.request_id(),
+    };
+    if let Some(request_id) = request_id {
+        span.record("request_id", request_id);
+    }
+    match result {
+        Ok(_) => tracing::debug!(status, "received response"),
+        Err(e) => {
+            if let Some(code) = e.code() {
+                span.record("error.code", code);
+            }
+            tracing::warn!(status, error = %e, "request failed");
+        }
+    }
+}
+
+pub fn record_error<R>(result: &ResponseResult<R>) {
+    if let Err(e) = result {
+        Span::current().record("error.code", "TransportError");
+        tracing::warn!(error = %e, "request failed");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Subscriber,
+    };
+    use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+    use crate::{
+        client::{Client, RateLimiter, RetryPolicy},
+        transport::MockTransport,
+        BatchUpdateFirmwareRequest, BatchUpdateFirmwareResponse, DescribeProductsRequest,
+        DescribeProductsResponse, Region,
+    };
+
+    /// Collects the fields of every span, and counts and names the events
+    /// inside them.
+    #[derive(Clone, Default)]
+    struct Recorder {
+        spans: Arc<Mutex<HashMap<u64, HashMap<String, String>>>>,
+    }
+
+    struct Fields<'a>(&'a mut HashMap<String, String>);
+
+    impl<'a> Visit for Fields<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for Recorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _: Context<'_, S>) {
+            let mut fields = HashMap::new();
+            fields.insert("name".to_string(), attrs.metadata().name().to_string());
+            attrs.record(&mut Fields(&mut fields));
+            self.spans.lock().unwrap().insert(id.into_u64(), fields);
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            let mut spans = self.spans.lock().unwrap();
+            values.record(&mut Fields(spans.get_mut(&id.into_u64()).unwrap()));
+        }
+
+        fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+            if let Some(span) = ctx.current_span().id() {
+                let mut spans = self.spans.lock().unwrap();
+                let fields = spans.get_mut(&span.into_u64()).unwrap();
+                let events = fields
+                    .entry("events".to_string())
+                    .or_insert_with(|| "0".to_string());
+                *events = (events.parse::<u64>().unwrap() + 1).to_string();
+
+                let mut event_fields = HashMap::new();
+                event.record(&mut Fields(&mut event_fields));
+                let messages = fields.entry("messages".to_string()).or_default();
+                if !messages.is_empty() {
+                    messages.push_str(", ");
+                }
+                messages.push_str(&event_fields["message"]);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_span() {
+        let recorder = Recorder::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+        let transport = MockTransport::new();
+        transport.respond(
+            "DescribeProducts",
+            r#"{"Response":{"RequestId":"req-1","TotalCount":0,"Products":[]}}"#,
+        );
+        transport.respond(
+            "BatchUpdateFirmware",
+            r#"{"Response":{"Error":{"Code":"InternalError","Message":"oops"},"RequestId":"req-2"}}"#,
+        );
+        let client = Client::builder()
+            .region(Region::APShanghai1)
+            .transport(transport)
+            .build()
+            .unwrap();
+        client
+            .iotcloud()
+            .describe_products(DescribeProductsRequest::builder())
+            .send::<DescribeProductsResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        client
+            .iotcloud()
+            .batch_update_firmware(
+                BatchUpdateFirmwareRequest::builder()
+                    .set_product_id("ABCDEFGHIJ".to_string())
+                    .set_firmware_version("1.0.1".to_string()),
+            )
+            .send::<BatchUpdateFirmwareResponse>()
+            .await
+            .unwrap()
+            .unwrap_err();
+
+        let spans = recorder.spans.lock().unwrap();
+        let mut spans: Vec<_> = spans.values().collect();
+        spans.sort_by_key(|fields| fields["request_id"].clone());
+        assert_eq!(spans.len(), 2);
+
+        let span = spans[0];
+        assert_eq!(span["name"], "tencentcloud.request");
+        assert_eq!(span["otel.name"], "iotcloud.DescribeProducts");
+        assert_eq!(span["region"], "ap-shanghai-1");
+        assert_eq!(span["endpoint"], "iotcloud.tencentcloudapi.com");
+        assert_eq!(span["http.status_code"], "200");
+        assert_eq!(span["request_id"], "req-1");
+        assert!(!span.contains_key("error.code"));
+        assert_eq!(span["events"], "2");
+
+        let span = spans[1];
+        assert_eq!(span["otel.name"], "iotcloud.BatchUpdateFirmware");
+        assert_eq!(span["request_id"], "req-2");
+        assert_eq!(span["error.code"], "InternalError");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_events() {
+        let recorder = Recorder::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "DescribeProducts",
+                r#"{"Response":{"Error":{"Code":"RequestLimitExceeded","Message":"slow down"},"RequestId":"req-1"}}"#,
+            )
+            .respond(
+                "DescribeProducts",
+                r#"{"Response":{"RequestId":"req-2","TotalCount":0,"Products":[]}}"#,
+            );
+        let client = Client::builder()
+            .transport(transport)
+            .rate_limiter(RateLimiter::builder().default_rate(1).build())
+            .retry_policy(RetryPolicy::new(1).base_delay(Duration::from_millis(100)))
+            .build()
+            .unwrap();
+        client
+            .iotcloud()
+            .describe_products(DescribeProductsRequest::builder())
+            .send::<DescribeProductsResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+
+        let spans = recorder.spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        let span = spans.values().next().unwrap();
+        assert_eq!(span["request_id"], "req-2");
+        // the retry waits for the rate limiter again, inside the span
+        assert_eq!(
+            span["messages"],
+            "sending request, request failed, retrying request, \
+             waited for the rate limiter, sending request, received response"
+        );
+    }
+
+    #[cfg(feature = "otel")]
+    #[tokio::test]
+    async fn test_traceparent() {
+        use opentelemetry::trace::TracerProvider as _;
+        use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::TracerProvider};
+
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+        let tracer = TracerProvider::builder().build().tracer("test");
+        let _guard = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer)),
+        );
+
+        let transport = MockTransport::new();
+        transport.respond(
+            "DescribeProducts",
+            r#"{"Response":{"RequestId":"req-1","TotalCount":0,"Products":[]}}"#,
+        );
+        let client = Client::builder()
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        client
+            .iotcloud()
+            .describe_products(DescribeProductsRequest::builder())
+            .send::<DescribeProductsResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+
+        let requests = transport.requests();
+        let traceparent = requests[0].headers["traceparent"].to_str().unwrap();
+        let parts: Vec<&str> = traceparent.split('-').collect();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0], "00");
+        assert_eq!(parts[1].len(), 32);
+        assert_eq!(parts[2].len(), 16);
+    }
+}
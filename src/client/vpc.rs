@@ -0,0 +1,457 @@
+use futures::stream::BoxStream;
+
+use crate::{request::vpc::*, ResponseResult};
+
+use super::{paginate::paginate, Client, ServiceClient};
+
+pub struct VPCClient {
+    client: Client,
+}
+
+impl VPCClient {
+    pub fn new(client: Client) -> VPCClient {
+        VPCClient { client }
+    }
+}
+
+impl VPCClient {
+    pub fn create_vpc(&self, req: CreateVpcRequestBuilder) -> ServiceClient<CreateVpcRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_vpcs(
+        &self,
+        req: DescribeVpcsRequestBuilder,
+    ) -> ServiceClient<DescribeVpcsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_vpc(&self, req: DeleteVpcRequestBuilder) -> ServiceClient<DeleteVpcRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_subnet(
+        &self,
+        req: CreateSubnetRequestBuilder,
+    ) -> ServiceClient<CreateSubnetRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_subnets(
+        &self,
+        req: DescribeSubnetsRequestBuilder,
+    ) -> ServiceClient<DescribeSubnetsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_subnet(
+        &self,
+        req: DeleteSubnetRequestBuilder,
+    ) -> ServiceClient<DeleteSubnetRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_security_group(
+        &self,
+        req: CreateSecurityGroupRequestBuilder,
+    ) -> ServiceClient<CreateSecurityGroupRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_security_groups(
+        &self,
+        req: DescribeSecurityGroupsRequestBuilder,
+    ) -> ServiceClient<DescribeSecurityGroupsRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_security_group(
+        &self,
+        req: DeleteSecurityGroupRequestBuilder,
+    ) -> ServiceClient<DeleteSecurityGroupRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_security_group_policies(
+        &self,
+        req: DescribeSecurityGroupPoliciesRequestBuilder,
+    ) -> ServiceClient<DescribeSecurityGroupPoliciesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_security_group_policies(
+        &self,
+        req: CreateSecurityGroupPoliciesRequestBuilder,
+    ) -> ServiceClient<CreateSecurityGroupPoliciesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn replace_security_group_policy(
+        &self,
+        req: ReplaceSecurityGroupPolicyRequestBuilder,
+    ) -> ServiceClient<ReplaceSecurityGroupPolicyRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_route_table(
+        &self,
+        req: CreateRouteTableRequestBuilder,
+    ) -> ServiceClient<CreateRouteTableRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_route_tables(
+        &self,
+        req: DescribeRouteTablesRequestBuilder,
+    ) -> ServiceClient<DescribeRouteTablesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_route_table(
+        &self,
+        req: DeleteRouteTableRequestBuilder,
+    ) -> ServiceClient<DeleteRouteTableRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_routes(
+        &self,
+        req: CreateRoutesRequestBuilder,
+    ) -> ServiceClient<CreateRoutesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn allocate_addresses(
+        &self,
+        req: AllocateAddressesRequestBuilder,
+    ) -> ServiceClient<AllocateAddressesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_addresses(
+        &self,
+        req: DescribeAddressesRequestBuilder,
+    ) -> ServiceClient<DescribeAddressesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn associate_address(
+        &self,
+        req: AssociateAddressRequestBuilder,
+    ) -> ServiceClient<AssociateAddressRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn disassociate_address(
+        &self,
+        req: DisassociateAddressRequestBuilder,
+    ) -> ServiceClient<DisassociateAddressRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn release_addresses(
+        &self,
+        req: ReleaseAddressesRequestBuilder,
+    ) -> ServiceClient<ReleaseAddressesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_nat_gateway(
+        &self,
+        req: CreateNatGatewayRequestBuilder,
+    ) -> ServiceClient<CreateNatGatewayRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_nat_gateways(
+        &self,
+        req: DescribeNatGatewaysRequestBuilder,
+    ) -> ServiceClient<DescribeNatGatewaysRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_nat_gateway(
+        &self,
+        req: DeleteNatGatewayRequestBuilder,
+    ) -> ServiceClient<DeleteNatGatewayRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn create_network_interface(
+        &self,
+        req: CreateNetworkInterfaceRequestBuilder,
+    ) -> ServiceClient<CreateNetworkInterfaceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn describe_network_interfaces(
+        &self,
+        req: DescribeNetworkInterfacesRequestBuilder,
+    ) -> ServiceClient<DescribeNetworkInterfacesRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn delete_network_interface(
+        &self,
+        req: DeleteNetworkInterfaceRequestBuilder,
+    ) -> ServiceClient<DeleteNetworkInterfaceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn attach_network_interface(
+        &self,
+        req: AttachNetworkInterfaceRequestBuilder,
+    ) -> ServiceClient<AttachNetworkInterfaceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    pub fn detach_network_interface(
+        &self,
+        req: DetachNetworkInterfaceRequestBuilder,
+    ) -> ServiceClient<DetachNetworkInterfaceRequest> {
+        ServiceClient::new(self.client.clone(), req)
+    }
+
+    /// Every VPC matching `req`, fetching `page_size` of them per call. The
+    /// offset and limit of `req` are overwritten.
+    pub fn describe_vpcs_stream(
+        &self,
+        req: DescribeVpcsRequestBuilder,
+        page_size: u64,
+    ) -> BoxStream<'static, ResponseResult<Vpc>> {
+        let client = self.client.clone();
+        let mut offset = 0;
+        paginate(
+            req.set_offset("0".to_string())
+                .set_limit(page_size.to_string()),
+            move |req| ServiceClient::new(client.clone(), req).send::<DescribeVpcsResponse>(),
+            move |req, resp| {
+                offset += resp.vpc_set.len() as u64;
+                let next = if resp.vpc_set.is_empty() || offset >= resp.total_count {
+                    None
+                } else {
+                    Some(req.clone().set_offset(offset.to_string()))
+                };
+                (resp.vpc_set, next)
+            },
+        )
+    }
+
+    /// Every subnet matching `req`, fetching `page_size` of them per call. The
+    /// offset and limit of `req` are overwritten.
+    pub fn describe_subnets_stream(
+        &self,
+        req: DescribeSubnetsRequestBuilder,
+        page_size: u64,
+    ) -> BoxStream<'static, ResponseResult<Subnet>> {
+        let client = self.client.clone();
+        let mut offset = 0;
+        paginate(
+            req.set_offset("0".to_string())
+                .set_limit(page_size.to_string()),
+            move |req| ServiceClient::new(client.clone(), req).send::<DescribeSubnetsResponse>(),
+            move |req, resp| {
+                offset += resp.subnet_set.len() as u64;
+                let next = if resp.subnet_set.is_empty() || offset >= resp.total_count {
+                    None
+                } else {
+                    Some(req.clone().set_offset(offset.to_string()))
+                };
+                (resp.subnet_set, next)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::StreamExt;
+
+    use crate::{
+        request::vpc::*,
+        transport::{
+            mock::{body, client},
+            MockTransport,
+        },
+        Flat,
+    };
+
+    #[tokio::test]
+    async fn test_security_group_policies() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "DescribeSecurityGroupPolicies",
+                r#"{"Response":{"SecurityGroupPolicySet":{"Version":"3","Egress":[],"Ingress":[{"PolicyIndex":0,"Protocol":"TCP","Port":"22","CidrBlock":"10.0.0.0/8","Action":"ACCEPT","PolicyDescription":"","ModifyTime":"2024-01-01 00:00:00"}]},"RequestId":"req"}}"#,
+            )
+            .respond(
+                "CreateSecurityGroupPolicies",
+                r#"{"Response":{"RequestId":"req"}}"#,
+            )
+            .respond(
+                "ReplaceSecurityGroupPolicy",
+                r#"{"Response":{"RequestId":"req"}}"#,
+            );
+        let vpc = client(&transport).vpc();
+
+        let set = SecurityGroupPolicySet::new()
+            .ingress(
+                SecurityGroupPolicy::new(PolicyAction::Accept)
+                    .protocol(Protocol::Tcp)
+                    .port("80,443")
+                    .cidr_block("0.0.0.0/0")
+                    .description("web"),
+            )
+            .ingress(
+                SecurityGroupPolicy::new(PolicyAction::Drop)
+                    .protocol(Protocol::All)
+                    .security_group("sg-other"),
+            );
+        let req = CreateSecurityGroupPoliciesRequest::builder()
+            .set_security_group_id("sg-1".to_string())
+            .set_security_group_policy_set(set);
+        vpc.create_security_group_policies(req)
+            .send::<CreateSecurityGroupPoliciesResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            body(&transport, "CreateSecurityGroupPolicies"),
+            r#"{"SecurityGroupId":"sg-1","SecurityGroupPolicySet":{"Ingress":[{"Protocol":"TCP","Port":"80,443","CidrBlock":"0.0.0.0/0","Action":"ACCEPT","PolicyDescription":"web"},{"Protocol":"ALL","SecurityGroupId":"sg-other","Action":"DROP"}]}}"#
+        );
+
+        let resp = vpc
+            .describe_security_group_policies(
+                DescribeSecurityGroupPoliciesRequest::builder()
+                    .set_security_group_id("sg-1".to_string()),
+            )
+            .send::<DescribeSecurityGroupPoliciesResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        let current = resp.security_group_policy_set.clone();
+        assert_eq!(current.ingress[0].protocol, Some(Protocol::Tcp));
+        assert_eq!(current.ingress[0].action, Some(PolicyAction::Accept));
+
+        let original = SecurityGroupPolicySet::new().ingress(current.ingress[0].clone());
+        let replacement = SecurityGroupPolicySet::new()
+            .version(current.version.unwrap())
+            .ingress(
+                SecurityGroupPolicy::new(PolicyAction::Accept)
+                    .index(0)
+                    .protocol(Protocol::Tcp)
+                    .port("22")
+                    .cidr_block("10.1.0.0/16"),
+            );
+        let req = ReplaceSecurityGroupPolicyRequest {
+            security_group_id: Some("sg-1".to_string()),
+            security_group_policy_set: Some(replacement),
+            original_security_group_policy_set: Some(original),
+        };
+        let hm = req.flat();
+        assert_eq!(hm["SecurityGroupPolicySet.Version"], "3");
+        assert_eq!(hm["SecurityGroupPolicySet.Ingress.0.PolicyIndex"], "0");
+        assert_eq!(
+            hm["SecurityGroupPolicySet.Ingress.0.CidrBlock"],
+            "10.1.0.0/16"
+        );
+        assert_eq!(
+            hm["OriginalSecurityGroupPolicySet.Ingress.0.CidrBlock"],
+            "10.0.0.0/8"
+        );
+        assert!(!hm.contains_key("OriginalSecurityGroupPolicySet.Ingress.0.ModifyTime"));
+
+        let builder = ReplaceSecurityGroupPolicyRequest::builder()
+            .set_security_group_id("sg-1".to_string())
+            .set_security_group_policy_set(req.security_group_policy_set.unwrap())
+            .set_original_security_group_policy_set(
+                req.original_security_group_policy_set.unwrap(),
+            );
+        vpc.replace_security_group_policy(builder)
+            .send::<ReplaceSecurityGroupPolicyResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            body(&transport, "ReplaceSecurityGroupPolicy"),
+            r#"{"SecurityGroupId":"sg-1","SecurityGroupPolicySet":{"Version":"3","Ingress":[{"PolicyIndex":0,"Protocol":"TCP","Port":"22","CidrBlock":"10.1.0.0/16","Action":"ACCEPT"}]},"OriginalSecurityGroupPolicySet":{"Ingress":[{"PolicyIndex":0,"Protocol":"TCP","Port":"22","CidrBlock":"10.0.0.0/8","Action":"ACCEPT","PolicyDescription":""}]}}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_describe_vpcs_stream() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "DescribeVpcs",
+                r#"{"Response":{"TotalCount":3,"VpcSet":[{"VpcId":"vpc-1"},{"VpcId":"vpc-2"}],"RequestId":"req"}}"#,
+            )
+            .respond(
+                "DescribeVpcs",
+                r#"{"Response":{"TotalCount":3,"VpcSet":[{"VpcId":"vpc-3","IsDefault":true}],"RequestId":"req"}}"#,
+            );
+        let vpc = client(&transport).vpc();
+
+        let req = DescribeVpcsRequest::builder()
+            .set_filters(vec![Filter::new("vpc-name", vec!["staging"])]);
+        let ids: Vec<_> = vpc
+            .describe_vpcs_stream(req, 2)
+            .map(|vpc| vpc.unwrap().unwrap().vpc_id)
+            .collect()
+            .await;
+        assert_eq!(ids, vec!["vpc-1", "vpc-2", "vpc-3"]);
+
+        let requests = transport.requests_for("DescribeVpcs");
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url, "https://vpc.tencentcloudapi.com/");
+        assert_eq!(requests[0].headers["X-TC-Version"], "2017-03-12");
+        assert_eq!(
+            body(&transport, "DescribeVpcs"),
+            r#"{"Filters":[{"Name":"vpc-name","Values":["staging"]}],"Offset":"2","Limit":"2"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_addresses() {
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "AllocateAddresses",
+                r#"{"Response":{"AddressSet":["eip-1"],"TaskId":"123","RequestId":"req"}}"#,
+            )
+            .respond(
+                "AssociateAddress",
+                r#"{"Response":{"TaskId":"124","RequestId":"req"}}"#,
+            );
+        let vpc = client(&transport).vpc();
+
+        let resp = vpc
+            .allocate_addresses(
+                AllocateAddressesRequest::builder()
+                    .set_address_count(1)
+                    .set_internet_max_bandwidth_out(10),
+            )
+            .send::<AllocateAddressesResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resp.address_set, vec!["eip-1"]);
+
+        let resp = vpc
+            .associate_address(
+                AssociateAddressRequest::builder()
+                    .set_address_id(resp.address_set[0].clone())
+                    .set_network_interface_id("eni-1".to_string())
+                    .set_private_ip_address("10.0.0.8".to_string()),
+            )
+            .send::<AssociateAddressResponse>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resp.task_id, "124");
+        assert_eq!(
+            body(&transport, "AssociateAddress"),
+            r#"{"AddressId":"eip-1","NetworkInterfaceId":"eni-1","PrivateIpAddress":"10.0.0.8"}"#
+        );
+    }
+}
@@ -0,0 +1,690 @@
+//! Cloud Object Storage.
+//!
+//! COS does not go through the `tencentcloudapi.com` JSON api: requests are
+//! sent to `<bucket>.cos.<region>.myqcloud.com`, bodies are XML and they are
+//! signed with the `q-sign-algorithm=sha1` scheme. The credential, region,
+//! transport, rate limiter and metrics of the `Client` are shared though.
+
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use futures::stream::BoxStream;
+use http::{HeaderMap, HeaderValue, Method};
+use md5::{Digest, Md5};
+
+use crate::{
+    client::{paginate::paginate, Configuration, MetricsRecorder, RateLimiter, RequestMetric},
+    response::Response,
+    transport::{HttpRequest, HttpResponse, HttpTransport, StreamingResponse},
+    Error, ResponseError, ResponseResult,
+};
+
+pub mod models;
+pub mod multipart;
+mod sign;
+
+pub use models::*;
+pub use multipart::MultipartUpload;
+
+pub const COS_ROOT_DOMAIN: &str = "myqcloud.com";
+
+/// How long the `Authorization` of a request stays valid.
+const SIGN_VALIDITY: Duration = Duration::from_secs(600);
+
+/// A client of one bucket, e.g. `examplebucket-1250000000`.
+#[derive(Clone)]
+pub struct CosClient {
+    transport: Arc<dyn HttpTransport>,
+    limiter: Option<RateLimiter>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    config: Configuration,
+    bucket: String,
+    host: HeaderValue,
+}
+
+/// A request on the bucket, before it is signed.
+struct CosRequest {
+    method: Method,
+    /// The object key, empty for requests on the bucket itself.
+    key: String,
+    params: BTreeMap<String, String>,
+    headers: HeaderMap,
+    body: Option<Vec<u8>>,
+}
+
+impl CosRequest {
+    fn new(method: Method, key: &str) -> CosRequest {
+        CosRequest {
+            method,
+            key: key.trim_start_matches('/').to_string(),
+            params: BTreeMap::new(),
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    fn param<S: ToString>(mut self, name: &str, value: S) -> Self {
+        self.params.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Sets the body along with its `Content-MD5`, which COS checks.
+    fn body(mut self, body: Vec<u8>) -> Self {
+        let md5 = base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            Md5::digest(&body),
+        );
+        self.headers
+            .insert("Content-MD5", HeaderValue::from_str(&md5).unwrap());
+        self.body = Some(body);
+        self
+    }
+}
+
+impl CosClient {
+    pub(crate) fn new(
+        transport: Arc<dyn HttpTransport>,
+        limiter: Option<RateLimiter>,
+        metrics: Option<Arc<dyn MetricsRecorder>>,
+        config: Configuration,
+        bucket: &str,
+    ) -> crate::Result<CosClient> {
+        let host = host_header(&format!(
+            "{}.cos.{}.{}",
+            bucket, config.region, COS_ROOT_DOMAIN
+        ))?;
+        Ok(CosClient {
+            transport,
+            limiter,
+            metrics,
+            config,
+            bucket: bucket.to_string(),
+            host,
+        })
+    }
+
+    /// Sends requests to `host` instead, e.g. a custom domain of the bucket
+    /// or the `cos.accelerate.myqcloud.com` endpoint.
+    pub fn host(mut self, host: &str) -> crate::Result<Self> {
+        self.host = host_header(host)?;
+        Ok(self)
+    }
+
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    fn url(&self, key: &str, params: &BTreeMap<String, String>) -> String {
+        let mut url = format!(
+            "{}://{}/{}",
+            self.config.profile.http.scheme.as_ref(),
+            String::from_utf8_lossy(self.host.as_bytes()),
+            sign::encode_path(key)
+        );
+        for (index, (name, value)) in params.iter().enumerate() {
+            url.push(if index == 0 { '?' } else { '&' });
+            url.push_str(&sign::encode(name));
+            if !value.is_empty() {
+                url.push('=');
+                url.push_str(&sign::encode(value));
+            }
+        }
+        url
+    }
+
+    fn sign(&self, req: CosRequest) -> HttpRequest {
+        let CosRequest {
+            method,
+            key,
+            params,
+            mut headers,
+            body,
+        } = req;
+        let credential = &self.config.credential;
+        headers.insert("Host", self.host.clone());
+        if let Some(token) = credential.token() {
+            headers.insert(
+                "x-cos-security-token",
+                HeaderValue::from_str(token).unwrap(),
+            );
+        }
+        let start = chrono::Local::now().timestamp();
+        let authorization = sign::authorization(
+            credential,
+            &method,
+            &format!("/{}", key),
+            &params,
+            &headers,
+            start,
+            start + SIGN_VALIDITY.as_secs() as i64,
+        );
+        headers.insert(
+            "Authorization",
+            HeaderValue::from_str(&authorization).unwrap(),
+        );
+        HttpRequest {
+            url: self.url(&key, &params),
+            method,
+            headers,
+            body,
+        }
+    }
+
+    /// Sends `req`, reading the body of error responses to decode them.
+    async fn call(
+        &self,
+        action: &'static str,
+        req: CosRequest,
+    ) -> ResponseResult<StreamingResponse> {
+        if let Some(ref limiter) = self.limiter {
+            limiter.acquire("cos", action).await;
+        }
+        let start = Instant::now();
+        let result = match self.transport.send_streaming(self.sign(req)).await {
+            Ok(response) if response.status < 300 => Ok(Ok(response)),
+            Ok(response) => response
+                .collect()
+                .await
+                .map(|response| Err(decode_error(&response))),
+            Err(e) => Err(e),
+        };
+        if let Some(ref metrics) = self.metrics {
+            let (status, error_code) = match result {
+                Ok(Ok(ref response)) => (Some(response.status), None),
+                Ok(Err(ref e)) => (None, Some(e.code().unwrap_or("BodyFormatError"))),
+                Err(_) => (None, Some("TransportError")),
+            };
+            metrics.record(&RequestMetric {
+                service: "cos",
+                action,
+                region: self.config.region.as_ref(),
+                latency: start.elapsed(),
+                status,
+                retries: 0,
+                error_code,
+            });
+        }
+        result
+    }
+
+    /// Sends `req` and reads the whole response.
+    async fn call_buffered(
+        &self,
+        action: &'static str,
+        req: CosRequest,
+    ) -> ResponseResult<HttpResponse> {
+        match self.call(action, req).await? {
+            Ok(response) => Ok(Ok(response.collect().await?)),
+            Err(e) => Ok(Err(e)),
+        }
+    }
+
+    /// Sends `req` and decodes its XML body.
+    async fn call_xml<R: serde::de::DeserializeOwned>(
+        &self,
+        action: &'static str,
+        req: CosRequest,
+    ) -> ResponseResult<Response<R>> {
+        let response = match self.call_buffered(action, req).await? {
+            Ok(response) => response,
+            Err(e) => return Ok(Err(e)),
+        };
+        let body = String::from_utf8_lossy(&response.body);
+        Ok(quick_xml::de::from_str(&body)
+            .map(|result| Response::new(result, request_id(&response.headers)))
+            .map_err(ResponseError::from))
+    }
+
+    pub async fn put_object<B: Into<Vec<u8>>>(
+        &self,
+        key: &str,
+        body: B,
+    ) -> ResponseResult<Response<PutObjectOutput>> {
+        let req = CosRequest::new(Method::PUT, key).body(body.into());
+        Ok(self.call_buffered("PutObject", req).await?.map(|response| {
+            let meta = ObjectMeta::from_headers(&response.headers);
+            let output = PutObjectOutput {
+                etag: meta.etag,
+                version_id: meta.version_id,
+            };
+            Response::new(output, request_id(&response.headers))
+        }))
+    }
+
+    /// Downloads an object. Its body is streamed, see `GetObjectOutput`.
+    pub async fn get_object(&self, key: &str) -> ResponseResult<Response<GetObjectOutput>> {
+        let req = CosRequest::new(Method::GET, key);
+        Ok(self.call("GetObject", req).await?.map(|response| {
+            let output = GetObjectOutput {
+                meta: ObjectMeta::from_headers(&response.headers),
+                body: response.body,
+            };
+            Response::new(output, request_id(&response.headers))
+        }))
+    }
+
+    pub async fn head_object(&self, key: &str) -> ResponseResult<Response<ObjectMeta>> {
+        let req = CosRequest::new(Method::HEAD, key);
+        Ok(self
+            .call_buffered("HeadObject", req)
+            .await?
+            .map(|response| {
+                let meta = ObjectMeta::from_headers(&response.headers);
+                Response::new(meta, request_id(&response.headers))
+            }))
+    }
+
+    /// Deleting a missing object succeeds too.
+    pub async fn delete_object(&self, key: &str) -> ResponseResult<Response<()>> {
+        let req = CosRequest::new(Method::DELETE, key);
+        Ok(self
+            .call_buffered("DeleteObject", req)
+            .await?
+            .map(|response| Response::new((), request_id(&response.headers))))
+    }
+
+    /// Lists one page of the objects of the bucket.
+    pub async fn list_objects(
+        &self,
+        req: &ListObjectsRequest,
+    ) -> ResponseResult<Response<ListBucketResult>> {
+        let mut cos_req = CosRequest::new(Method::GET, "");
+        let params = [
+            ("prefix", req.prefix.clone()),
+            ("delimiter", req.delimiter.clone()),
+            ("marker", req.marker.clone()),
+            (
+                "max-keys",
+                req.max_keys.map(|max_keys| max_keys.to_string()),
+            ),
+        ];
+        for (name, value) in params.iter() {
+            if let Some(value) = value {
+                cos_req = cos_req.param(name, value);
+            }
+        }
+        self.call_xml("ListObjects", cos_req).await
+    }
+
+    /// Every object matching `req`, one page at a time. The marker of `req`
+    /// is where the listing starts.
+    pub fn list_objects_stream(
+        &self,
+        req: ListObjectsRequest,
+    ) -> BoxStream<'static, ResponseResult<ObjectSummary>> {
+        let client = self.clone();
+        paginate(
+            req,
+            move |req| {
+                let client = client.clone();
+                async move { client.list_objects(&req).await }
+            },
+            |req, resp: ListBucketResult| {
+                let next = match resp.next_marker {
+                    Some(marker) if resp.is_truncated => Some(ListObjectsRequest {
+                        marker: Some(marker),
+                        ..req.clone()
+                    }),
+                    _ => None,
+                };
+                (resp.contents, next)
+            },
+        )
+    }
+
+    /// A URL anyone can `method` the object with until `expires` from now,
+    /// e.g. to hand a firmware download to a device.
+    pub fn presigned_url(&self, method: Method, key: &str, expires: Duration) -> String {
+        let key = key.trim_start_matches('/');
+        let mut headers = HeaderMap::new();
+        headers.insert("Host", self.host.clone());
+        let start = chrono::Local::now().timestamp();
+        let authorization = sign::authorization(
+            &self.config.credential,
+            &method,
+            &format!("/{}", key),
+            &BTreeMap::new(),
+            &headers,
+            start,
+            start + expires.as_secs() as i64,
+        );
+        let mut url = self.url(key, &BTreeMap::new());
+        url.push('?');
+        // the authorization is already made of `name=value` pairs
+        url.push_str(&authorization.replace(';', "%3B"));
+        if let Some(token) = self.config.credential.token() {
+            url.push_str("&x-cos-security-token=");
+            url.push_str(&sign::encode(token));
+        }
+        url
+    }
+}
+
+/// Checks `host` is a `name[:port]` that can go in both the url and the
+/// `Host` header of a request.
+fn host_header(host: &str) -> crate::Result<HeaderValue> {
+    let valid = !host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':' | '[' | ']'));
+    match HeaderValue::from_str(host) {
+        Ok(header) if valid => Ok(header),
+        _ => Err(Error::Configuration(format!("invalid COS host {:?}", host))),
+    }
+}
+
+fn request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("x-cos-request-id")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// COS describes errors in an `<Error>` body, except for `HEAD` requests,
+/// whose code is made up from the status.
+fn decode_error(response: &HttpResponse) -> ResponseError {
+    let body = String::from_utf8_lossy(&response.body);
+    match quick_xml::de::from_str::<CosError>(&body) {
+        Ok(error) => ResponseError::Api {
+            code: error.code,
+            message: error.message,
+            request_id: error.request_id,
+        },
+        Err(_) => {
+            let reason = http::StatusCode::from_u16(response.status)
+                .ok()
+                .and_then(|status| status.canonical_reason())
+                .unwrap_or("Unknown");
+            ResponseError::Api {
+                code: reason.replace(' ', ""),
+                message: format!("http status {}", response.status),
+                request_id: request_id(&response.headers).unwrap_or_default(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use http::Method;
+
+    use super::*;
+    use crate::{client::Client, transport::MockTransport, Credential, Region};
+
+    fn cos(transport: &MockTransport) -> CosClient {
+        Client::builder()
+            .transport(transport.clone())
+            .region(Region::APGuangzhou)
+            .credential(
+                Credential::builder()
+                    .access_key("AKID")
+                    .secret_key("secret")
+                    .build(),
+            )
+            .build()
+            .unwrap()
+            .cos("firmware-1250000000")
+            .unwrap()
+    }
+
+    fn response<B: Into<Vec<u8>>>(status: u16, body: B, headers: &[(&str, &str)]) -> HttpResponse {
+        let mut response = HttpResponse::new(status, body);
+        for (name, value) in headers {
+            response.headers.insert(
+                http::header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        response
+    }
+
+    #[tokio::test]
+    async fn test_objects() {
+        let transport = MockTransport::new();
+        transport
+            .respond_with(
+                "PUT",
+                response(
+                    200,
+                    "",
+                    &[("etag", "\"abc\""), ("x-cos-request-id", "req-put")],
+                ),
+            )
+            .respond_with(
+                "GET",
+                response(
+                    200,
+                    "firmware",
+                    &[("content-length", "8"), ("x-cos-meta-build", "42")],
+                ),
+            )
+            .respond_with(
+                "HEAD",
+                response(404, "", &[("x-cos-request-id", "req-head")]),
+            )
+            .respond_with("DELETE", response(204, "", &[]));
+        let cos = cos(&transport);
+
+        let resp = cos
+            .put_object("/v1.0.0/fw.bin", "firmware")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resp.etag.as_deref(), Some("\"abc\""));
+        assert_eq!(resp.request_id(), Some("req-put"));
+        let req = &transport.requests_for("PUT")[0];
+        assert_eq!(
+            req.url,
+            "https://firmware-1250000000.cos.ap-guangzhou.myqcloud.com/v1.0.0/fw.bin"
+        );
+        assert_eq!(req.headers["Content-MD5"], "dLW16VcO/FwFU7syfNQZQA==");
+        let authorization = req.headers["Authorization"].to_str().unwrap();
+        assert!(authorization.starts_with("q-sign-algorithm=sha1&q-ak=AKID&"));
+        assert!(authorization.contains("&q-header-list=content-md5;host&"));
+
+        let object = cos
+            .get_object("v1.0.0/fw.bin")
+            .await
+            .unwrap()
+            .unwrap()
+            .into_inner();
+        assert_eq!(object.meta.content_length, 8);
+        assert_eq!(object.meta.metadata["build"], "42");
+        assert_eq!(object.bytes().await.unwrap(), b"firmware");
+
+        let err = cos.head_object("missing").await.unwrap().unwrap_err();
+        assert_eq!(err.code(), Some("NotFound"));
+
+        cos.delete_object("v1.0.0/fw.bin").await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let transport = MockTransport::new();
+        transport.respond_with(
+            "GET",
+            response(
+                403,
+                "<?xml version='1.0' encoding='utf-8' ?><Error><Code>AccessDenied</Code><Message>Access Denied.</Message><RequestId>req-1</RequestId></Error>",
+                &[],
+            ),
+        );
+        let err = cos(&transport)
+            .get_object("fw.bin")
+            .await
+            .unwrap()
+            .unwrap_err();
+        match err {
+            ResponseError::Api {
+                code, request_id, ..
+            } => {
+                assert_eq!(code, "AccessDenied");
+                assert_eq!(request_id, "req-1");
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    fn page(keys: &[&str], next: Option<&str>) -> String {
+        let contents: String = keys
+            .iter()
+            .map(|key| format!("<Contents><Key>{}</Key><Size>1</Size></Contents>", key))
+            .collect();
+        let next = next
+            .map(|next| format!("<NextMarker>{}</NextMarker>", next))
+            .unwrap_or_default();
+        format!(
+            "<ListBucketResult><Name>firmware-1250000000</Name><Prefix>v1/</Prefix><MaxKeys>2</MaxKeys><IsTruncated>{}</IsTruncated>{}{}</ListBucketResult>",
+            !next.is_empty(),
+            next,
+            contents
+        )
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_stream() {
+        let transport = MockTransport::new();
+        transport
+            .respond("GET", page(&["v1/a", "v1/b"], Some("v1/b")))
+            .respond("GET", page(&["v1/c"], None));
+        let req = ListObjectsRequest {
+            max_keys: Some(2),
+            ..ListObjectsRequest::prefix("v1/")
+        };
+        let keys: Vec<String> = cos(&transport)
+            .list_objects_stream(req)
+            .map(|object| object.unwrap().unwrap().key)
+            .collect()
+            .await;
+        assert_eq!(keys, ["v1/a", "v1/b", "v1/c"]);
+        let requests = transport.requests_for("GET");
+        assert_eq!(
+            requests[0].url,
+            "https://firmware-1250000000.cos.ap-guangzhou.myqcloud.com/?max-keys=2&prefix=v1%2F"
+        );
+        assert_eq!(
+            requests[1].url,
+            "https://firmware-1250000000.cos.ap-guangzhou.myqcloud.com/?marker=v1%2Fb&max-keys=2&prefix=v1%2F"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resume_multipart_upload() {
+        let part_size = multipart::MIN_PART_SIZE as usize;
+        let mut content = vec![b'a'; part_size];
+        content.extend(vec![b'b'; part_size]);
+        content.extend(b"tail");
+        let path = std::env::temp_dir().join(format!("cos-multipart-{}", std::process::id()));
+        tokio::fs::write(&path, &content).await.unwrap();
+
+        let first = format!("\"{}\"", hex::encode(Md5::digest(&content[..part_size])));
+        let transport = MockTransport::new();
+        transport
+            .respond(
+                "GET",
+                format!(
+                    "<ListPartsResult><IsTruncated>false</IsTruncated><Part><PartNumber>1</PartNumber><ETag>{}</ETag><Size>{}</Size></Part><Part><PartNumber>4</PartNumber><ETag>\"stale\"</ETag><Size>1</Size></Part></ListPartsResult>",
+                    first, part_size
+                ),
+            )
+            .respond_with("PUT", response(200, "", &[("etag", "\"uploaded\"")]))
+            .respond(
+                "POST",
+                "<CompleteMultipartUploadResult><Key>fw.bin</Key><ETag>\"abc-3\"</ETag></CompleteMultipartUploadResult>",
+            );
+
+        let mut upload = cos(&transport)
+            .resume_multipart_upload("fw.bin", "upload-1")
+            .await
+            .unwrap()
+            .unwrap();
+        let result = upload
+            .upload_file(&path, part_size as u64)
+            .await
+            .unwrap()
+            .unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+        assert_eq!(result.etag, "\"abc-3\"");
+
+        let puts = transport.requests_for("PUT");
+        assert_eq!(puts.len(), 2);
+        assert!(puts[0]
+            .url
+            .ends_with("/fw.bin?partNumber=2&uploadId=upload-1"));
+        assert_eq!(puts[1].body.as_deref(), Some(&b"tail"[..]));
+        let complete =
+            String::from_utf8(transport.requests_for("POST")[0].body.clone().unwrap()).unwrap();
+        // the stale part 4 is dropped, the quotes of the ETags are escaped
+        assert_eq!(
+            complete,
+            format!(
+                "<CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>{}</ETag></Part><Part><PartNumber>2</PartNumber><ETag>\"uploaded\"</ETag></Part><Part><PartNumber>3</PartNumber><ETag>\"uploaded\"</ETag></Part></CompleteMultipartUpload>",
+                first
+            )
+            .replace('"', "&quot;")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_too_many_parts() {
+        let path = std::env::temp_dir().join(format!("cos-sparse-{}", std::process::id()));
+        let len = u64::from(multipart::MAX_PARTS) * multipart::MIN_PART_SIZE + 1;
+        std::fs::File::create(&path).unwrap().set_len(len).unwrap();
+
+        let transport = MockTransport::new();
+        transport.respond(
+            "GET",
+            "<ListPartsResult><IsTruncated>false</IsTruncated></ListPartsResult>",
+        );
+        let mut upload = cos(&transport)
+            .resume_multipart_upload("fw.bin", "upload-1")
+            .await
+            .unwrap()
+            .unwrap();
+        let err = upload
+            .upload_file(&path, multipart::MIN_PART_SIZE)
+            .await
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.to_string().contains("10001 parts, at most 10000"));
+        assert!(transport.requests_for("PUT").is_empty());
+    }
+
+    #[test]
+    fn test_presigned_url() {
+        let url = cos(&MockTransport::new()).presigned_url(
+            Method::GET,
+            "firmware/v1 (rc).bin",
+            Duration::from_secs(3600),
+        );
+        assert!(url.starts_with(
+            "https://firmware-1250000000.cos.ap-guangzhou.myqcloud.com/firmware/v1%20%28rc%29.bin?q-sign-algorithm=sha1&q-ak=AKID&q-sign-time="
+        ));
+        assert!(url.contains("&q-header-list=host&q-url-param-list=&q-signature="));
+    }
+
+    #[test]
+    fn test_host() {
+        let client = cos(&MockTransport::new())
+            .host("firmware.example.com:8443")
+            .unwrap();
+        let url = client.presigned_url(Method::GET, "a.bin", Duration::from_secs(60));
+        assert!(url.starts_with("https://firmware.example.com:8443/a.bin?"));
+
+        for host in ["", "firmware.example.com/path", "firmware\nexample.com"].iter() {
+            let err = cos(&MockTransport::new()).host(host).err().unwrap();
+            assert!(matches!(err, Error::Configuration(_)), "{:?}", host);
+        }
+        let client = Client::builder()
+            .transport(MockTransport::new())
+            .build()
+            .unwrap();
+        assert!(client.cos("firmware 1250000000").is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use futures::{stream::BoxStream, TryStreamExt};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The `<Error>` body COS answers failed requests with.
+#[derive(Deserialize, Debug)]
+pub(crate) struct CosError {
+    #[serde(rename = "Code")]
+    pub code: String,
+    #[serde(rename = "Message", default)]
+    pub message: String,
+    #[serde(rename = "RequestId", default)]
+    pub request_id: String,
+}
+
+/// Metadata of an object, from the headers of `HeadObject` and `GetObject`.
+#[derive(Debug, Clone, Default)]
+pub struct ObjectMeta {
+    pub content_length: u64,
+    pub content_type: Option<String>,
+    /// Quoted, e.g. `"5d99b396caab4bfae4e25207b62b30e2"`.
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub version_id: Option<String>,
+    /// The `x-cos-meta-*` headers, without the prefix.
+    pub metadata: HashMap<String, String>,
+}
+
+impl ObjectMeta {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> ObjectMeta {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let metadata = headers
+            .iter()
+            .filter_map(|(name, value)| {
+                let name = name.as_str().strip_prefix("x-cos-meta-")?;
+                Some((name.to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect();
+        ObjectMeta {
+            content_length: header("content-length")
+                .and_then(|len| len.parse().ok())
+                .unwrap_or_default(),
+            content_type: header("content-type"),
+            etag: header("etag"),
+            last_modified: header("last-modified"),
+            version_id: header("x-cos-version-id"),
+            metadata,
+        }
+    }
+}
+
+/// An object being downloaded. The body is streamed as it arrives.
+pub struct GetObjectOutput {
+    pub meta: ObjectMeta,
+    pub body: BoxStream<'static, crate::Result<Bytes>>,
+}
+
+impl GetObjectOutput {
+    /// Reads the rest of the body into memory.
+    pub async fn bytes(self) -> crate::Result<Vec<u8>> {
+        let chunks: Vec<Bytes> = self.body.try_collect().await?;
+        Ok(chunks.concat())
+    }
+}
+
+impl std::fmt::Debug for GetObjectOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GetObjectOutput")
+            .field("meta", &self.meta)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PutObjectOutput {
+    pub etag: Option<String>,
+    pub version_id: Option<String>,
+}
+
+/// Parameters of `GET Bucket` (List Objects). COS pages with `marker`, the
+/// key to start after, which `NextMarker` of the previous page provides.
+#[derive(Debug, Clone, Default)]
+pub struct ListObjectsRequest {
+    pub prefix: Option<String>,
+    /// e.g. `/` to list one level of a directory tree.
+    pub delimiter: Option<String>,
+    pub marker: Option<String>,
+    /// At most 1000.
+    pub max_keys: Option<u64>,
+}
+
+impl ListObjectsRequest {
+    pub fn prefix<S: Into<String>>(prefix: S) -> ListObjectsRequest {
+        ListObjectsRequest {
+            prefix: Some(prefix.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ListBucketResult {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Prefix", default)]
+    pub prefix: Option<String>,
+    #[serde(rename = "Marker", default)]
+    pub marker: Option<String>,
+    #[serde(rename = "MaxKeys", default)]
+    pub max_keys: u64,
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    /// Only set when `IsTruncated` is.
+    #[serde(rename = "NextMarker", default)]
+    pub next_marker: Option<String>,
+    /// Keys grouped by `delimiter`.
+    #[serde(rename = "CommonPrefixes", default)]
+    pub common_prefixes: Vec<CommonPrefix>,
+    #[serde(rename = "Contents", default)]
+    pub contents: Vec<ObjectSummary>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CommonPrefix {
+    #[serde(rename = "Prefix")]
+    pub prefix: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ObjectSummary {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "LastModified", default)]
+    pub last_modified: String,
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+    #[serde(rename = "Size", default)]
+    pub size: u64,
+    #[serde(rename = "StorageClass", default)]
+    pub storage_class: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct InitiateMultipartUploadResult {
+    #[serde(rename = "UploadId")]
+    pub upload_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ListPartsResult {
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "NextPartNumberMarker", default)]
+    pub next_part_number_marker: Option<u32>,
+    #[serde(rename = "Part", default)]
+    pub parts: Vec<Part>,
+}
+
+/// A part already uploaded to a multipart upload.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Part {
+    #[serde(rename = "PartNumber")]
+    pub part_number: u32,
+    /// Quoted md5 of the part.
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "Size", default)]
+    pub size: u64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename = "CompleteMultipartUpload")]
+pub(crate) struct CompleteMultipartUpload {
+    #[serde(rename = "Part")]
+    pub parts: Vec<CompletedPart>,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct CompletedPart {
+    #[serde(rename = "PartNumber")]
+    pub part_number: u32,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CompleteMultipartUploadResult {
+    #[serde(rename = "Location", default)]
+    pub location: String,
+    #[serde(rename = "Bucket", default)]
+    pub bucket: String,
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+}
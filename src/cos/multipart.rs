@@ -0,0 +1,222 @@
+use std::{collections::BTreeMap, path::Path};
+
+use http::Method;
+use md5::{Digest, Md5};
+use tokio::io::AsyncReadExt;
+
+use crate::{response::Response, Error, ResponseResult};
+
+use super::{
+    models::{
+        CompleteMultipartUpload, CompleteMultipartUploadResult, CompletedPart,
+        InitiateMultipartUploadResult, ListPartsResult, Part,
+    },
+    CosClient, CosRequest,
+};
+
+/// Smallest part COS accepts, except for the last one.
+pub const MIN_PART_SIZE: u64 = 1024 * 1024;
+/// Most parts one upload may have.
+pub const MAX_PARTS: u32 = 10000;
+
+/// An upload of an object in parts.
+///
+/// Parts already uploaded are skipped when uploaded again with the same
+/// content, so an interrupted upload resumes from its `upload_id`:
+///
+/// ```ignore
+/// let upload = cos.resume_multipart_upload("firmware/2.0.0.bin", &upload_id).await??;
+/// upload.upload_file("2.0.0.bin", 8 * 1024 * 1024).await??;
+/// ```
+#[derive(Clone)]
+pub struct MultipartUpload {
+    client: CosClient,
+    key: String,
+    upload_id: String,
+    parts: BTreeMap<u32, Part>,
+}
+
+impl CosClient {
+    pub async fn create_multipart_upload(
+        &self,
+        key: &str,
+    ) -> ResponseResult<Response<MultipartUpload>> {
+        let req = CosRequest::new(Method::POST, key).param("uploads", "");
+        let result = self
+            .call_xml::<InitiateMultipartUploadResult>("InitiateMultipartUpload", req)
+            .await?;
+        Ok(result.map(|resp| {
+            let request_id = resp.request_id().map(str::to_string);
+            let upload = MultipartUpload {
+                client: self.clone(),
+                key: key.to_string(),
+                upload_id: resp.into_inner().upload_id,
+                parts: BTreeMap::new(),
+            };
+            Response::new(upload, request_id)
+        }))
+    }
+
+    /// Picks up an upload created earlier, listing the parts it already has.
+    pub async fn resume_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+    ) -> ResponseResult<MultipartUpload> {
+        let mut upload = MultipartUpload {
+            client: self.clone(),
+            key: key.to_string(),
+            upload_id: upload_id.to_string(),
+            parts: BTreeMap::new(),
+        };
+        let mut marker = None;
+        loop {
+            let mut req = CosRequest::new(Method::GET, key).param("uploadId", upload_id);
+            if let Some(marker) = marker {
+                req = req.param("part-number-marker", marker);
+            }
+            let resp = match self.call_xml::<ListPartsResult>("ListParts", req).await? {
+                Ok(resp) => resp.into_inner(),
+                Err(e) => return Ok(Err(e)),
+            };
+            for part in resp.parts {
+                upload.parts.insert(part.part_number, part);
+            }
+            match resp.next_part_number_marker {
+                Some(next) if resp.is_truncated => marker = Some(next),
+                _ => return Ok(Ok(upload)),
+            }
+        }
+    }
+}
+
+impl MultipartUpload {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Keep it to resume the upload if it gets interrupted.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// The parts uploaded so far.
+    pub fn parts(&self) -> impl Iterator<Item = &Part> {
+        self.parts.values()
+    }
+
+    /// Uploads part `part_number`, from 1, unless the upload already has it
+    /// with the same content.
+    pub async fn upload_part(&mut self, part_number: u32, body: Vec<u8>) -> ResponseResult<()> {
+        if part_number == 0 || part_number > MAX_PARTS {
+            return Err(Error::InvalidRequest(format!(
+                "UploadPart: part number {} is not in 1..={}",
+                part_number, MAX_PARTS
+            )));
+        }
+        let etag = format!("\"{}\"", hex::encode(Md5::digest(&body)));
+        let size = body.len() as u64;
+        if let Some(part) = self.parts.get(&part_number) {
+            if part.etag == etag && part.size == size {
+                return Ok(Ok(()));
+            }
+        }
+        let req = CosRequest::new(Method::PUT, &self.key)
+            .param("partNumber", part_number)
+            .param("uploadId", &self.upload_id)
+            .body(body);
+        match self.client.call_buffered("UploadPart", req).await? {
+            Ok(response) => {
+                let etag = response
+                    .headers
+                    .get("etag")
+                    .and_then(|etag| etag.to_str().ok())
+                    .map(str::to_string)
+                    .unwrap_or(etag);
+                let part = Part {
+                    part_number,
+                    etag,
+                    size,
+                };
+                self.parts.insert(part_number, part);
+                Ok(Ok(()))
+            }
+            Err(e) => Ok(Err(e)),
+        }
+    }
+
+    /// Uploads the file at `path` in parts of `part_size` bytes and
+    /// completes the upload. The file may not need more than `MAX_PARTS`
+    /// parts, which is checked before any part is sent.
+    pub async fn upload_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        part_size: u64,
+    ) -> ResponseResult<Response<CompleteMultipartUploadResult>> {
+        if part_size < MIN_PART_SIZE {
+            return Err(Error::InvalidRequest(format!(
+                "UploadPart: parts must be at least {} bytes",
+                MIN_PART_SIZE
+            )));
+        }
+        let mut file = tokio::fs::File::open(path).await?;
+        let len = file.metadata().await?.len();
+        let parts = len.div_ceil(part_size).max(1);
+        if parts > u64::from(MAX_PARTS) {
+            return Err(Error::InvalidRequest(format!(
+                "UploadPart: {} bytes in parts of {} bytes make {} parts, at most {} are allowed",
+                len, part_size, parts, MAX_PARTS
+            )));
+        }
+        let mut part_number = 1;
+        loop {
+            let mut body = Vec::with_capacity(part_size as usize);
+            (&mut file).take(part_size).read_to_end(&mut body).await?;
+            if body.is_empty() && part_number > 1 {
+                part_number -= 1;
+                break;
+            }
+            let last = (body.len() as u64) < part_size;
+            if let Err(e) = self.upload_part(part_number, body).await? {
+                return Ok(Err(e));
+            }
+            if last {
+                break;
+            }
+            part_number += 1;
+        }
+        // parts past the end of the file are left over from another attempt
+        self.parts.retain(|&number, _| number <= part_number);
+        self.complete().await
+    }
+
+    /// Assembles the parts uploaded so far into the object.
+    pub async fn complete(&self) -> ResponseResult<Response<CompleteMultipartUploadResult>> {
+        let body = CompleteMultipartUpload {
+            parts: self
+                .parts
+                .values()
+                .map(|part| CompletedPart {
+                    part_number: part.part_number,
+                    etag: part.etag.clone(),
+                })
+                .collect(),
+        };
+        let body = quick_xml::se::to_string(&body)
+            .map_err(|e| Error::InvalidRequest(format!("CompleteMultipartUpload: {}", e)))?;
+        let req = CosRequest::new(Method::POST, &self.key)
+            .param("uploadId", &self.upload_id)
+            .body(body.into_bytes());
+        self.client.call_xml("CompleteMultipartUpload", req).await
+    }
+
+    /// Drops the upload and the parts uploaded so far.
+    pub async fn abort(self) -> ResponseResult<Response<()>> {
+        let req = CosRequest::new(Method::DELETE, &self.key).param("uploadId", &self.upload_id);
+        Ok(self
+            .client
+            .call_buffered("AbortMultipartUpload", req)
+            .await?
+            .map(|response| Response::new((), super::request_id(&response.headers))))
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use http::{HeaderMap, Method};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use sha1::{Digest, Sha1};
+
+use crate::Credential;
+
+/// Everything but the unreserved characters of RFC 3986.
+const QUERY: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Like `QUERY`, but keeps the `/` separating the parts of an object key.
+const PATH: &AsciiSet = &QUERY.remove(b'/');
+
+pub(crate) fn encode(s: &str) -> String {
+    utf8_percent_encode(s, QUERY).to_string()
+}
+
+pub(crate) fn encode_path(s: &str) -> String {
+    utf8_percent_encode(s, PATH).to_string()
+}
+
+/// Builds the `Authorization` value of the COS `q-sign-algorithm=sha1`
+/// scheme for a request valid from `start` to `end` (unix seconds).
+///
+/// `path` is the decoded path of the request, e.g. `/firmware/1.0.0.bin`,
+/// and every header of `headers` is signed.
+pub(crate) fn authorization(
+    credential: &Credential,
+    method: &Method,
+    path: &str,
+    params: &BTreeMap<String, String>,
+    headers: &HeaderMap,
+    start: i64,
+    end: i64,
+) -> String {
+    let key_time = format!("{};{}", start, end);
+    let sign_key = hex::encode(hmacsha1(
+        key_time.as_bytes(),
+        credential.secret_key().as_bytes(),
+    ));
+
+    let (param_list, http_params) = canonical(
+        params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str())),
+    );
+    let (header_list, http_headers) = canonical(
+        headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?))),
+    );
+    let http_string = format!(
+        "{}\n{}\n{}\n{}\n",
+        method.as_str().to_lowercase(),
+        path,
+        http_params,
+        http_headers
+    );
+    let string_to_sign = format!(
+        "sha1\n{}\n{}\n",
+        key_time,
+        hex::encode(Sha1::digest(http_string.as_bytes()))
+    );
+    let signature = hex::encode(hmacsha1(string_to_sign.as_bytes(), sign_key.as_bytes()));
+
+    format!(
+        "q-sign-algorithm=sha1&q-ak={}&q-sign-time={}&q-key-time={}&q-header-list={}&q-url-param-list={}&q-signature={}",
+        credential.access_key(),
+        key_time,
+        key_time,
+        header_list,
+        param_list,
+        signature
+    )
+}
+
+/// The `;` separated list of the lowercased, encoded names, and the sorted
+/// `name=value` pairs joined with `&`.
+fn canonical<'a, I: Iterator<Item = (&'a str, &'a str)>>(pairs: I) -> (String, String) {
+    let pairs: BTreeMap<String, String> = pairs
+        .map(|(name, value)| (encode(&name.to_lowercase()), encode(value)))
+        .collect();
+    let names: Vec<&str> = pairs.keys().map(String::as_str).collect();
+    let joined: Vec<String> = pairs
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    (names.join(";"), joined.join("&"))
+}
+
+fn hmacsha1(s: &[u8], key: &[u8]) -> Vec<u8> {
+    use hmac::{Hmac, Mac, NewMac};
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(s);
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use http::{HeaderMap, Method};
+
+    use crate::Credential;
+
+    #[test]
+    fn test_authorization() {
+        let credential = Credential::builder()
+            .access_key("AKIDQjz3ltompVjBni5LitkWHFlFpwkn9U5q")
+            .secret_key("BQYIM75p8x0iWVFSIgqEKwFprpRSVHlz")
+            .build();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "Host",
+            "examplebucket-1250000000.cos.ap-beijing.myqcloud.com"
+                .parse()
+                .unwrap(),
+        );
+        headers.insert("Content-Type", "text/plain".parse().unwrap());
+        headers.insert("x-cos-meta-author", "Jane Doe".parse().unwrap());
+        let mut params = BTreeMap::new();
+        params.insert("versionId".to_string(), "a b".to_string());
+        params.insert("acl".to_string(), String::new());
+
+        let auth = super::authorization(
+            &credential,
+            &Method::PUT,
+            "/exampleobject(腾讯云)",
+            &params,
+            &headers,
+            1557989151,
+            1557996351,
+        );
+        assert_eq!(
+            auth,
+            "q-sign-algorithm=sha1&q-ak=AKIDQjz3ltompVjBni5LitkWHFlFpwkn9U5q&q-sign-time=1557989151;1557996351&q-key-time=1557989151;1557996351&q-header-list=content-type;host;x-cos-meta-author&q-url-param-list=acl;versionid&q-signature=5e27c3390051aaaa591fa6d351137190f4900f0c"
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(super::encode("a b/c~d"), "a%20b%2Fc~d");
+        assert_eq!(
+            super::encode_path("firmware/v1 (rc).bin"),
+            "firmware/v1%20%28rc%29.bin"
+        );
+    }
+}
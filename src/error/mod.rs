@@ -1,11 +1,28 @@
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
+    #[cfg(feature = "reqwest")]
     #[error("http error with {0}")]
     HTTP(
         #[from]
         #[source]
         reqwest::Error,
     ),
+    #[error("transport error with {0}")]
+    Transport(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("invalid request url {0}")]
+    InvalidUrl(String),
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("io error with {0}")]
+    IO(
+        #[from]
+        #[source]
+        std::io::Error,
+    ),
+    #[error("invalid configuration: {0}")]
+    Configuration(String),
+    #[error("no http transport configured, enable the `reqwest` feature")]
+    MissingTransport,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -14,6 +31,51 @@ pub enum ResponseError {
     BodyFormat(
         #[from]
         #[source]
-        reqwest::Error,
+        serde_json::Error,
     ),
+    #[error("decode xml response body with wrong format: {0}")]
+    XmlFormat(
+        #[from]
+        #[source]
+        quick_xml::DeError,
+    ),
+    #[error("decode base64 payload of the response: {0}")]
+    Payload(
+        #[from]
+        #[source]
+        base64::DecodeError,
+    ),
+    #[error("api error {code}: {message} (request id: {request_id})")]
+    Api {
+        code: String,
+        message: String,
+        request_id: String,
+    },
+}
+
+impl ResponseError {
+    /// The Tencent Cloud error code, e.g. `RequestLimitExceeded`.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            ResponseError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ResponseError::Api { request_id, .. } => Some(request_id),
+            _ => None,
+        }
+    }
+}
+
+impl From<crate::response::ErrorResponse> for ResponseError {
+    fn from(resp: crate::response::ErrorResponse) -> Self {
+        ResponseError::Api {
+            code: resp.error.code,
+            message: resp.error.message,
+            request_id: resp.request_id,
+        }
+    }
 }
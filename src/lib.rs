@@ -1,10 +1,16 @@
+#[macro_use]
+mod macros;
+
 pub mod client;
+pub mod cos;
 pub mod credential;
 pub mod error;
 pub mod profile;
 pub mod region;
 pub mod request;
 pub mod response;
+pub mod transport;
+pub mod waiter;
 
 pub use credential::*;
 pub use error::{Error, ResponseError};
@@ -12,6 +18,7 @@ pub use profile::*;
 pub use region::Region;
 pub use request::*;
 pub use scheme::*;
+pub use transport::{HttpRequest, HttpResponse, HttpTransport, StreamingResponse};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -0,0 +1,153 @@
+/// Serializes a type as its `AsRef<str>` form and deserializes it through `FromStr`.
+/// The same form is used when the type is flattened into request parameters.
+macro_rules! serde_str {
+    ($ty:ty) => {
+        impl crate::FlatField for $ty {
+            fn flat_into(&self, hm: &mut std::collections::HashMap<String, String>, key: &str) {
+                hm.insert(key.to_string(), self.as_ref().to_string());
+            }
+        }
+
+        impl serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_ref())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// Declares a request: the struct itself, its builder, and the `Serialize`,
+/// `Flat`, `ServiceRequest` and `IntoRequest` plumbing every action needs.
+///
+/// ```ignore
+/// request! {
+///     /// doc comment of the request
+///     DescribeDeviceRequest, DescribeDeviceRequestBuilder => ("iotcloud", "DescribeDevice", API_VERSION) {
+///         product_id: String => "ProductID",
+///         device_name: String => "DeviceName",
+///     }
+/// }
+/// ```
+macro_rules! request {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $builder:ident => ($service:expr, $action:expr, $version:expr) {
+            $( $(#[$fmeta:meta])* $field:ident : $ty:ty => $key:expr ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Default, Debug, Clone)]
+        pub struct $name {
+            $( $(#[$fmeta])* pub $field: Option<$ty>, )*
+        }
+
+        impl $name {
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+
+                // serde_json closes the object right away when told it has no fields
+                let len = 0 $( + self.$field.is_some() as usize )*;
+                #[allow(unused_mut)]
+                let mut state = serializer.serialize_struct(stringify!($name), len)?;
+                $(
+                    if let Some(ref $field) = self.$field {
+                        state.serialize_field($key, $field)?;
+                    }
+                )*
+                state.end()
+            }
+        }
+
+        impl crate::ServiceRequest for $name {
+            fn service(&self) -> &'static str {
+                $service
+            }
+
+            fn action(&self) -> &'static str {
+                $action
+            }
+        }
+
+        impl crate::Flat for $name {
+            fn flat(&self) -> std::collections::HashMap<String, String> {
+                #[allow(unused_mut)]
+                let mut hm = std::collections::HashMap::new();
+                $( crate::FlatField::flat_into(&self.$field, &mut hm, $key); )*
+                hm
+            }
+        }
+
+        #[derive(Default, Clone)]
+        pub struct $builder {
+            req: $name,
+        }
+
+        impl $builder {
+            paste::paste! {
+                $(
+                    pub fn [<set_ $field>](mut self, $field: $ty) -> Self {
+                        self.req.$field = Some($field);
+                        self
+                    }
+                )*
+            }
+        }
+
+        impl crate::IntoRequest for $builder {
+            type Request = $name;
+
+            fn into_request(
+                self,
+                config: crate::client::Configuration,
+            ) -> crate::RequestBuilder<Self::Request> {
+                crate::RequestBuilder::from_config(config, $version, self.req)
+            }
+        }
+    };
+}
+
+/// Implements `FlatField` for a struct nested in a request, writing each
+/// field under `<key>.<Name>`, or for a `Copy` enum sent as its integer form.
+///
+/// ```ignore
+/// flat_field!(Placement { zone => "Zone", project_id => "ProjectId" });
+/// flat_field!(RegisterType as i64);
+/// ```
+macro_rules! flat_field {
+    ($ty:ty as $repr:ty) => {
+        impl crate::FlatField for $ty {
+            fn flat_into(&self, hm: &mut std::collections::HashMap<String, String>, key: &str) {
+                crate::FlatField::flat_into(&<$repr>::from(*self), hm, key);
+            }
+        }
+    };
+    ($ty:ty { $( $field:ident => $key:expr ),* $(,)? }) => {
+        impl crate::FlatField for $ty {
+            fn flat_into(&self, hm: &mut std::collections::HashMap<String, String>, key: &str) {
+                $( crate::FlatField::flat_into(&self.$field, hm, &format!("{}.{}", key, $key)); )*
+            }
+        }
+    };
+}
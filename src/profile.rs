@@ -1,6 +1,7 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
-use reqwest::Method;
+use http::Method;
+use serde::{Deserialize, Deserializer};
 
 use crate::{Scheme, ROOT_DOMAIN};
 
@@ -27,6 +28,21 @@ impl AsRef<str> for SignMethod {
     }
 }
 
+serde_str!(SignMethod);
+
+impl FromStr for SignMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TC3-HMAC-SHA256" => Ok(SignMethod::Tc3HmacSha256),
+            "HmacSHA1" => Ok(SignMethod::HmacSHA1),
+            "HmacSHA256" => Ok(SignMethod::HmacSHA256),
+            _ => Err(format!("unknown sign method: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Language {
     ZHCN,
@@ -42,6 +58,20 @@ impl AsRef<str> for Language {
     }
 }
 
+serde_str!(Language);
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zh-CN" => Ok(Language::ZHCN),
+            "en-US" => Ok(Language::ENUS),
+            _ => Err(format!("unknown language: {}", s)),
+        }
+    }
+}
+
 impl Default for Language {
     fn default() -> Self {
         Language::ZHCN
@@ -60,8 +90,11 @@ impl Profile {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize)]
+#[serde(default)]
 pub struct ClientProfile {
+    /// Only `TC3-HMAC-SHA256` is supported.
+    #[serde(rename = "sign_method", alias = "sing_method")]
     pub sing_method: SignMethod,
     pub unsigned_payload: bool,
     pub language: Language,
@@ -83,13 +116,25 @@ impl Default for ClientProfile {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize)]
+#[serde(default)]
 pub struct HTTProfile {
-    pub method: reqwest::Method,
+    /// Only `POST` is supported.
+    #[serde(deserialize_with = "deserialize_method")]
+    pub method: Method,
     pub timeout: u64,
     pub scheme: Scheme,
     pub root_domain: String,
     pub endpoint: String,
+    /// Explicit proxy. When unset, `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` from
+    /// the environment are honored.
+    pub proxy: Option<ProxyConfig>,
+    /// Extra PEM encoded root certificates trusted besides the system ones.
+    #[serde(deserialize_with = "deserialize_pems")]
+    pub root_certificates: Vec<Vec<u8>>,
+    /// Client certificate for mutual TLS.
+    pub identity: Option<TlsIdentity>,
+    pub tls_backend: TlsBackend,
 }
 
 impl Default for HTTProfile {
@@ -100,6 +145,165 @@ impl Default for HTTProfile {
             scheme: Default::default(),
             root_domain: ROOT_DOMAIN.to_string(),
             endpoint: Default::default(),
+            proxy: Default::default(),
+            root_certificates: Default::default(),
+            identity: Default::default(),
+            tls_backend: Default::default(),
         }
     }
 }
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProxyConfig {
+    /// e.g. `http://proxy.internal:3128`
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Comma separated hosts that bypass the proxy, same format as `NO_PROXY`.
+    /// Falls back to the `NO_PROXY` environment variable when unset.
+    pub no_proxy: Option<String>,
+}
+
+impl ProxyConfig {
+    pub fn new<S: Into<String>>(url: S) -> ProxyConfig {
+        ProxyConfig {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn basic_auth(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
+    pub fn no_proxy(mut self, no_proxy: &str) -> Self {
+        self.no_proxy = Some(no_proxy.to_string());
+        self
+    }
+
+    /// Reads `HTTPS_PROXY` (or `HTTP_PROXY`) and `NO_PROXY`, upper or lower case.
+    pub fn from_env() -> Option<ProxyConfig> {
+        let url = env_any(&["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"])?;
+        Some(ProxyConfig {
+            url,
+            no_proxy: env_any(&["NO_PROXY", "no_proxy"]),
+            ..Default::default()
+        })
+    }
+}
+
+fn env_any(keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .find(|value| !value.is_empty())
+}
+
+/// PEM encoded client certificate chain and its PKCS#8 private key.
+#[derive(Clone, Deserialize)]
+pub struct TlsIdentity {
+    #[serde(deserialize_with = "deserialize_pem")]
+    pub certificate: Vec<u8>,
+    #[serde(deserialize_with = "deserialize_pem")]
+    pub private_key: Vec<u8>,
+}
+
+impl std::fmt::Debug for TlsIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TlsIdentity").finish()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TlsBackend {
+    /// Whatever the transport is compiled with.
+    #[default]
+    Default,
+    NativeTls,
+    Rustls,
+}
+
+fn deserialize_method<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Method, D::Error> {
+    let method = String::deserialize(deserializer)?;
+    Method::from_bytes(method.to_uppercase().as_bytes()).map_err(serde::de::Error::custom)
+}
+
+/// PEM documents are text, so they are read from strings rather than byte arrays.
+fn deserialize_pem<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    Ok(String::deserialize(deserializer)?.into_bytes())
+}
+
+fn deserialize_pems<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error> {
+    let pems = Vec::<String>::deserialize(deserializer)?;
+    Ok(pems.into_iter().map(String::into_bytes).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use http::Method;
+
+    use crate::{Region, Scheme};
+
+    use super::{ClientProfile, HTTProfile, Language, SignMethod, TlsBackend};
+
+    #[test]
+    fn test_wire_strings() {
+        assert_eq!(
+            serde_json::to_string(&Region::APGuangzhou).unwrap(),
+            r#""ap-guangzhou""#
+        );
+        assert_eq!(
+            serde_json::to_string(&SignMethod::Tc3HmacSha256).unwrap(),
+            r#""TC3-HMAC-SHA256""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Language::ENUS).unwrap(),
+            r#""en-US""#
+        );
+        assert_eq!(serde_json::to_string(&Scheme::HTTP).unwrap(), r#""http""#);
+
+        let region: Region = serde_json::from_str(r#""ap-shanghai-2""#).unwrap();
+        assert_eq!(region.as_ref(), "ap-shanghai-2");
+        let sign_method: SignMethod = serde_json::from_str(r#""HmacSHA256""#).unwrap();
+        assert!(matches!(sign_method, SignMethod::HmacSHA256));
+        let err = serde_json::from_str::<Region>(r#""ap-mars""#).unwrap_err();
+        assert_eq!(err.to_string(), "unknown region: ap-mars");
+    }
+
+    #[test]
+    fn test_deserialize_profiles() {
+        let client: ClientProfile = serde_json::from_str(
+            r#"{"sign_method": "HmacSHA1", "language": "en-US", "debug": true}"#,
+        )
+        .unwrap();
+        assert!(matches!(client.sing_method, SignMethod::HmacSHA1));
+        assert!(matches!(client.language, Language::ENUS));
+        assert!(client.debug);
+        assert!(!client.unsigned_payload);
+
+        let http: HTTProfile = serde_json::from_str(
+            r#"{
+                "method": "get",
+                "scheme": "http",
+                "endpoint": "iotcloud.internal.tencentcloudapi.com",
+                "proxy": {"url": "http://proxy:3128", "no_proxy": "localhost"},
+                "root_certificates": ["-----BEGIN CERTIFICATE-----"],
+                "tls_backend": "native-tls"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(http.method, Method::GET);
+        assert!(matches!(http.scheme, Scheme::HTTP));
+        assert_eq!(http.timeout, 60);
+        assert_eq!(http.root_domain, "tencentcloudapi.com");
+        assert_eq!(http.proxy.unwrap().no_proxy.as_deref(), Some("localhost"));
+        assert_eq!(
+            http.root_certificates,
+            vec![b"-----BEGIN CERTIFICATE-----".to_vec()]
+        );
+        assert_eq!(http.tls_backend, TlsBackend::NativeTls);
+    }
+}
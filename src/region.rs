@@ -3,9 +3,10 @@ use std::{
     str::FromStr,
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Region {
     // guangzhou
+    APGuangzhou,  // "ap-guangzhou"
     APGuangzhou1, // "ap-guangzhou-1"
     APGuangzhou2, // "ap-guangzhou-2"
     APGuangzhou3, // "ap-guangzhou-3"
@@ -13,6 +14,7 @@ pub enum Region {
     APGuangzhou6, // "ap-guangzhou-6"
 
     // shanghai
+    APShanghai,  // "ap-shanghai"
     APShanghai1, // "ap-shanghai-1"
     APShanghai2, // "ap-shanghai-2"
     APShanghai3, // "ap-shanghai-3"
@@ -20,6 +22,7 @@ pub enum Region {
     APShanghai5, // "ap-shanghai-5"
 
     // nanjing
+    APNanjing,  // "ap-nanjing"
     APNanjing1, // "ap-nanjing-1"
     APNanjing2, // "ap-nanjing-2"
 
@@ -34,13 +37,16 @@ pub enum Region {
     APBeijing7, // "ap-beijing-7"
 
     // chengdu
+    APChengdu,  // "ap-chengdu"
     APChengdu1, // "ap-chengdu-1"
     APChengdu2, // "ap-chengdu-2"
 
     // chongqing
+    APChongqing,  // "ap-chongqing"
     APChongqing1, // "ap-chongqing-1"
 
     // hongkong
+    APHongkong,  // "ap-hongkong"
     APHongkong1, // "ap-hongkong-1"
     APHongkong2, // "ap-hongkong-2"
     APHongkong3, // "ap-hongkong-3"
@@ -49,16 +55,19 @@ pub enum Region {
 impl Debug for Region {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::APGuangzhou => write!(f, "ap-guangzhou"),
             Self::APGuangzhou1 => write!(f, "ap-guangzhou-1"),
             Self::APGuangzhou2 => write!(f, "ap-guangzhou-2"),
             Self::APGuangzhou3 => write!(f, "ap-guangzhou-3"),
             Self::APGuangzhou4 => write!(f, "ap-guangzhou-4"),
             Self::APGuangzhou6 => write!(f, "ap-guangzhou-6"),
+            Self::APShanghai => write!(f, "ap-shanghai"),
             Self::APShanghai1 => write!(f, "ap-shanghai-1"),
             Self::APShanghai2 => write!(f, "ap-shanghai-2"),
             Self::APShanghai3 => write!(f, "ap-shanghai-3"),
             Self::APShanghai4 => write!(f, "ap-shanghai-4"),
             Self::APShanghai5 => write!(f, "ap-shanghai-5"),
+            Self::APNanjing => write!(f, "ap-nanjing"),
             Self::APNanjing1 => write!(f, "ap-nanjing-1"),
             Self::APNanjing2 => write!(f, "ap-nanjing-2"),
             Self::APBeijing => write!(f, "ap-beijing"),
@@ -69,9 +78,12 @@ impl Debug for Region {
             Self::APBeijing5 => write!(f, "ap-beijing-5"),
             Self::APBeijing6 => write!(f, "ap-beijing-6"),
             Self::APBeijing7 => write!(f, "ap-beijing-7"),
+            Self::APChengdu => write!(f, "ap-chengdu"),
             Self::APChengdu1 => write!(f, "ap-chengdu-1"),
             Self::APChengdu2 => write!(f, "ap-chengdu-2"),
+            Self::APChongqing => write!(f, "ap-chongqing"),
             Self::APChongqing1 => write!(f, "ap-chongqing-1"),
+            Self::APHongkong => write!(f, "ap-hongkong"),
             Self::APHongkong1 => write!(f, "ap-hongkong-1"),
             Self::APHongkong2 => write!(f, "ap-hongkong-2"),
             Self::APHongkong3 => write!(f, "ap-hongkong-3"),
@@ -82,16 +94,19 @@ impl Debug for Region {
 impl AsRef<str> for Region {
     fn as_ref(&self) -> &str {
         match self {
+            Region::APGuangzhou => "ap-guangzhou",
             Region::APGuangzhou1 => "ap-guangzhou-1",
             Region::APGuangzhou2 => "ap-guangzhou-2",
             Region::APGuangzhou3 => "ap-guangzhou-3",
             Region::APGuangzhou4 => "ap-guangzhou-4",
             Region::APGuangzhou6 => "ap-guangzhou-6",
+            Region::APShanghai => "ap-shanghai",
             Region::APShanghai1 => "ap-shanghai-1",
             Region::APShanghai2 => "ap-shanghai-2",
             Region::APShanghai3 => "ap-shanghai-3",
             Region::APShanghai4 => "ap-shanghai-4",
             Region::APShanghai5 => "ap-shanghai-5",
+            Region::APNanjing => "ap-nanjing",
             Region::APNanjing1 => "ap-nanjing-1",
             Region::APNanjing2 => "ap-nanjing-2",
             Region::APBeijing => "ap-beijing",
@@ -102,9 +117,12 @@ impl AsRef<str> for Region {
             Region::APBeijing5 => "ap-beijing-5",
             Region::APBeijing6 => "ap-beijing-6",
             Region::APBeijing7 => "ap-beijing-7",
+            Region::APChengdu => "ap-chengdu",
             Region::APChengdu1 => "ap-chengdu-1",
             Region::APChengdu2 => "ap-chengdu-2",
+            Region::APChongqing => "ap-chongqing",
             Region::APChongqing1 => "ap-chongqing-1",
+            Region::APHongkong => "ap-hongkong",
             Region::APHongkong1 => "ap-hongkong-1",
             Region::APHongkong2 => "ap-hongkong-2",
             Region::APHongkong3 => "ap-hongkong-3",
@@ -117,16 +135,19 @@ impl FromStr for Region {
 
     fn from_str(region: &str) -> Result<Self, Self::Err> {
         match region {
+            "ap-guangzhou" => Ok(Region::APGuangzhou),
             "ap-guangzhou-1" => Ok(Region::APGuangzhou1),
             "ap-guangzhou-2" => Ok(Region::APGuangzhou2),
             "ap-guangzhou-3" => Ok(Region::APGuangzhou3),
             "ap-guangzhou-4" => Ok(Region::APGuangzhou4),
             "ap-guangzhou-6" => Ok(Region::APGuangzhou6),
+            "ap-shanghai" => Ok(Region::APShanghai),
             "ap-shanghai-1" => Ok(Region::APShanghai1),
             "ap-shanghai-2" => Ok(Region::APShanghai2),
             "ap-shanghai-3" => Ok(Region::APShanghai3),
             "ap-shanghai-4" => Ok(Region::APShanghai4),
             "ap-shanghai-5" => Ok(Region::APShanghai5),
+            "ap-nanjing" => Ok(Region::APNanjing),
             "ap-nanjing-1" => Ok(Region::APNanjing1),
             "ap-nanjing-2" => Ok(Region::APNanjing2),
             "ap-beijing" => Ok(Region::APBeijing),
@@ -137,9 +158,12 @@ impl FromStr for Region {
             "ap-beijing-5" => Ok(Region::APBeijing5),
             "ap-beijing-6" => Ok(Region::APBeijing6),
             "ap-beijing-7" => Ok(Region::APBeijing7),
+            "ap-chengdu" => Ok(Region::APChengdu),
             "ap-chengdu-1" => Ok(Region::APChengdu1),
             "ap-chengdu-2" => Ok(Region::APChengdu2),
+            "ap-chongqing" => Ok(Region::APChongqing),
             "ap-chongqing-1" => Ok(Region::APChongqing1),
+            "ap-hongkong" => Ok(Region::APHongkong),
             "ap-hongkong-1" => Ok(Region::APHongkong1),
             "ap-hongkong-2" => Ok(Region::APHongkong2),
             "ap-hongkong-3" => Ok(Region::APHongkong3),
@@ -148,6 +172,8 @@ impl FromStr for Region {
     }
 }
 
+serde_str!(Region);
+
 impl Display for Region {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         (self as &dyn Debug).fmt(f)
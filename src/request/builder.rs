@@ -1,19 +1,23 @@
 use std::{
     collections::HashMap,
+    convert::TryFrom,
     fmt::Debug,
     ops::{Deref, DerefMut},
     sync::Arc,
 };
 
-use crate::{Credential, Flat, Language, Profile, Region, Scheme, SignMethod, ROOT_DOMAIN};
-use reqwest::{header::HeaderMap, Method};
+use crate::{
+    client::Configuration, transport::HttpRequest, Credential, Flat, Language, Profile, Region,
+    Scheme, SignMethod, ROOT_DOMAIN,
+};
+use http::{HeaderMap, Method};
 use sha2::{Digest, Sha256};
 
 use super::ServiceRequest;
 
 #[derive(Debug)]
 pub struct RequestBuilder<T> {
-    pub method: reqwest::Method,
+    pub method: Method,
     pub scheme: Scheme,
     pub root_domain: String,
     pub domain: Option<String>,
@@ -59,6 +63,27 @@ impl<T: Default> Default for RequestBuilder<T> {
     }
 }
 
+impl<T: Default> RequestBuilder<T> {
+    /// A request of `inner` to the api `version`, with the credential,
+    /// region, scheme, root domain and language of `config`.
+    pub fn from_config(config: Configuration, version: &str, inner: T) -> Self {
+        let scheme = config.profile.http.scheme;
+        let root_domain = config.profile.http.root_domain.clone();
+        let language = config.profile.client.language.clone();
+        Self {
+            scheme,
+            root_domain,
+            language,
+            credential: Some(config.credential),
+            profile: Some(config.profile),
+            region: config.region,
+            version: Some(version.to_string()),
+            inner,
+            ..Default::default()
+        }
+    }
+}
+
 impl<T> Deref for RequestBuilder<T> {
     type Target = T;
 
@@ -74,7 +99,7 @@ impl<T> DerefMut for RequestBuilder<T> {
 }
 
 impl<T> RequestBuilder<T> {
-    pub fn set_method(mut self, method: reqwest::Method) -> Self {
+    pub fn set_method(mut self, method: Method) -> Self {
         self.method = method;
         self
     }
@@ -234,6 +259,9 @@ impl<T> RequestBuilder<T> {
             "X-TC-RequestClient",
             self.params.get("RequestClient").unwrap().parse().unwrap(),
         );
+        // TC3 requests carry the region in a header rather than in the body
+        self.headers
+            .insert("X-TC-Region", self.region.as_ref().parse().unwrap());
         self.headers
             .insert("X-TC-Language", self.language.as_ref().parse().unwrap());
         if self.method == Method::GET {
@@ -423,13 +451,18 @@ where
     }
 }
 
-impl<T> From<RequestBuilder<T>> for reqwest::Request
+impl<T> TryFrom<RequestBuilder<T>> for HttpRequest
 where
     T: Flat + Debug + ServiceRequest + serde::Serialize,
 {
-    fn from(rb: RequestBuilder<T>) -> Self {
-        let mut rb = rb.ensure().unwrap();
-        let u = format!(
+    type Error = crate::Error;
+
+    fn try_from(rb: RequestBuilder<T>) -> crate::Result<Self> {
+        rb.inner.validate()?;
+        let mut rb = rb
+            .ensure()
+            .ok_or_else(|| crate::Error::InvalidRequest("failed to sign request".to_string()))?;
+        let url = format!(
             "{}://{}{}",
             rb.scheme.as_ref(),
             rb.domain.as_ref().unwrap(),
@@ -438,12 +471,12 @@ where
 
         // dbg!(&rb);
 
-        let mut request = reqwest::Request::new(rb.method.clone(), u.parse().unwrap());
-        if let Some(payload) = rb.payload.take() {
-            *request.body_mut() = Some(payload.into());
-        }
-        *request.headers_mut() = rb.headers;
-        request
+        Ok(HttpRequest {
+            method: rb.method.clone(),
+            url,
+            headers: rb.headers,
+            body: rb.payload.take().map(String::into_bytes),
+        })
     }
 }
 
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+use super::{HealthCheck, API_VERSION};
+
+request! {
+    CreateListenerRequest, CreateListenerRequestBuilder => ("clb", "CreateListener", API_VERSION) {
+        load_balancer_id: String => "LoadBalancerId",
+        /// One listener is created per port.
+        ports: Vec<i64> => "Ports",
+        /// `TCP`, `UDP`, `HTTP`, `HTTPS` or `TCP_SSL`.
+        protocol: String => "Protocol",
+        /// One name per port.
+        listener_names: Vec<String> => "ListenerNames",
+        /// Layer 4 listeners only.
+        health_check: HealthCheck => "HealthCheck",
+        /// Layer 4 listeners only.
+        session_expire_time: i64 => "SessionExpireTime",
+        /// `WRR` or `LEAST_CONN`; layer 4 listeners only.
+        scheduler: String => "Scheduler",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateListenerResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "ListenerIds", default)]
+    pub listener_ids: Vec<String>,
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+use super::{TagInfo, API_VERSION};
+
+request! {
+    /// Creates load balancers. They are ready once the task of the returned
+    /// `RequestId` is done, see `CLBClient::wait_task`.
+    CreateLoadBalancerRequest, CreateLoadBalancerRequestBuilder => ("clb", "CreateLoadBalancer", API_VERSION) {
+        /// `OPEN` or `INTERNAL`.
+        load_balancer_type: String => "LoadBalancerType",
+        /// 1 for a CLB, 0 for a classic CLB.
+        forward: i64 => "Forward",
+        load_balancer_name: String => "LoadBalancerName",
+        vpc_id: String => "VpcId",
+        /// Required for an `INTERNAL` load balancer.
+        subnet_id: String => "SubnetId",
+        project_id: i64 => "ProjectId",
+        /// `IPV4` or `IPv6FullChain`.
+        address_ip_version: String => "AddressIPVersion",
+        /// Load balancers to create, 1 by default.
+        number: i64 => "Number",
+        zone_id: String => "ZoneId",
+        tags: Vec<TagInfo> => "Tags",
+        /// Makes retries idempotent.
+        client_token: String => "ClientToken",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateLoadBalancerResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "LoadBalancerIds", default)]
+    pub load_balancer_ids: Vec<String>,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{RuleInput, API_VERSION};
+
+request! {
+    /// Adds forwarding rules to a layer 7 listener.
+    CreateRuleRequest, CreateRuleRequestBuilder => ("clb", "CreateRule", API_VERSION) {
+        load_balancer_id: String => "LoadBalancerId",
+        listener_id: String => "ListenerId",
+        rules: Vec<RuleInput> => "Rules",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateRuleResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "LocationIds", default)]
+    pub location_ids: Vec<String>,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{Target, API_VERSION};
+
+request! {
+    DeregisterTargetsRequest, DeregisterTargetsRequestBuilder => ("clb", "DeregisterTargets", API_VERSION) {
+        load_balancer_id: String => "LoadBalancerId",
+        listener_id: String => "ListenerId",
+        targets: Vec<Target> => "Targets",
+        location_id: String => "LocationId",
+        domain: String => "Domain",
+        url: String => "Url",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeregisterTargetsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::{Listener, API_VERSION};
+
+request! {
+    DescribeListenersRequest, DescribeListenersRequestBuilder => ("clb", "DescribeListeners", API_VERSION) {
+        load_balancer_id: String => "LoadBalancerId",
+        listener_ids: Vec<String> => "ListenerIds",
+        protocol: String => "Protocol",
+        port: i64 => "Port",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeListenersResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount", default)]
+    pub total_count: Option<u64>,
+    #[serde(rename = "Listeners", default)]
+    pub listeners: Vec<Listener>,
+}
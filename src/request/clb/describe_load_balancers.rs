@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+use super::{Filter, LoadBalancer, API_VERSION};
+
+request! {
+    DescribeLoadBalancersRequest, DescribeLoadBalancersRequestBuilder => ("clb", "DescribeLoadBalancers", API_VERSION) {
+        load_balancer_ids: Vec<String> => "LoadBalancerIds",
+        /// `OPEN` or `INTERNAL`.
+        load_balancer_type: String => "LoadBalancerType",
+        load_balancer_name: String => "LoadBalancerName",
+        vpc_id: String => "VpcId",
+        /// e.g. `zone` or `tag-key`.
+        filters: Vec<Filter> => "Filters",
+        offset: i64 => "Offset",
+        /// At most 100.
+        limit: i64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeLoadBalancersResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "LoadBalancerSet", default)]
+    pub load_balancer_set: Vec<LoadBalancer>,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::{LoadBalancerHealth, API_VERSION};
+
+request! {
+    DescribeTargetHealthRequest, DescribeTargetHealthRequestBuilder => ("clb", "DescribeTargetHealth", API_VERSION) {
+        load_balancer_ids: Vec<String> => "LoadBalancerIds",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeTargetHealthResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "LoadBalancers", default)]
+    pub load_balancers: Vec<LoadBalancerHealth>,
+}
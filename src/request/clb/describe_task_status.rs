@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::{TaskStatus, API_VERSION};
+
+request! {
+    /// Queries the task of a mutating call; its id is the `RequestId` of that
+    /// call.
+    DescribeTaskStatusRequest, DescribeTaskStatusRequestBuilder => ("clb", "DescribeTaskStatus", API_VERSION) {
+        task_id: String => "TaskId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeTaskStatusResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Status")]
+    pub status: TaskStatus,
+}
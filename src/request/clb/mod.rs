@@ -0,0 +1,25 @@
+pub mod create_listener;
+pub mod create_load_balancer;
+pub mod create_rule;
+pub mod deregister_targets;
+pub mod describe_listeners;
+pub mod describe_load_balancers;
+pub mod describe_target_health;
+pub mod describe_task_status;
+pub mod models;
+pub mod modify_target_weight;
+pub mod register_targets;
+
+pub use create_listener::*;
+pub use create_load_balancer::*;
+pub use create_rule::*;
+pub use deregister_targets::*;
+pub use describe_listeners::*;
+pub use describe_load_balancers::*;
+pub use describe_target_health::*;
+pub use describe_task_status::*;
+pub use models::*;
+pub use modify_target_weight::*;
+pub use register_targets::*;
+
+pub const API_VERSION: &str = "2018-03-17";
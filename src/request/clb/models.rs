@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+
+/// CLB shares the filter type of CVM.
+pub use crate::request::cvm::Filter;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TagInfo {
+    #[serde(rename = "TagKey")]
+    pub tag_key: String,
+    #[serde(rename = "TagValue")]
+    pub tag_value: String,
+}
+
+flat_field!(TagInfo {
+    tag_key => "TagKey",
+    tag_value => "TagValue",
+});
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LoadBalancer {
+    #[serde(rename = "LoadBalancerId")]
+    pub load_balancer_id: String,
+    #[serde(rename = "LoadBalancerName", default)]
+    pub load_balancer_name: String,
+    /// `OPEN` or `INTERNAL`.
+    #[serde(rename = "LoadBalancerType", default)]
+    pub load_balancer_type: String,
+    /// 1 for a CLB, 0 for a classic CLB.
+    #[serde(rename = "Forward", default)]
+    pub forward: i64,
+    #[serde(rename = "Domain", default)]
+    pub domain: Option<String>,
+    #[serde(rename = "LoadBalancerVips", default)]
+    pub load_balancer_vips: Vec<String>,
+    /// 0 while creating, 1 when running.
+    #[serde(rename = "Status", default)]
+    pub status: u64,
+    #[serde(rename = "VpcId", default)]
+    pub vpc_id: String,
+    #[serde(rename = "SubnetId", default)]
+    pub subnet_id: Option<String>,
+    #[serde(rename = "ProjectId", default)]
+    pub project_id: i64,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: String,
+    #[serde(rename = "Tags", default)]
+    pub tags: Vec<TagInfo>,
+}
+
+/// Health check of a listener or a forwarding rule. Unset fields are left
+/// to the defaults of the api.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct HealthCheck {
+    /// 1 to enable health checks, 0 to disable them.
+    #[serde(
+        rename = "HealthSwitch",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub health_switch: Option<i64>,
+    /// In seconds.
+    #[serde(rename = "TimeOut", default, skip_serializing_if = "Option::is_none")]
+    pub time_out: Option<i64>,
+    /// In seconds.
+    #[serde(
+        rename = "IntervalTime",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub interval_time: Option<i64>,
+    /// Successes before a target is healthy again.
+    #[serde(rename = "HealthNum", default, skip_serializing_if = "Option::is_none")]
+    pub health_num: Option<i64>,
+    /// Failures before a target is unhealthy.
+    #[serde(
+        rename = "UnHealthNum",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub un_health_num: Option<i64>,
+    /// Bit mask of the healthy status classes: 1 for 1xx, 2 for 2xx, 4 for
+    /// 3xx, 8 for 4xx and 16 for 5xx.
+    #[serde(rename = "HttpCode", default, skip_serializing_if = "Option::is_none")]
+    pub http_code: Option<i64>,
+    #[serde(
+        rename = "HttpCheckPath",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub http_check_path: Option<String>,
+    #[serde(
+        rename = "HttpCheckDomain",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub http_check_domain: Option<String>,
+    /// `HEAD` or `GET`.
+    #[serde(
+        rename = "HttpCheckMethod",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub http_check_method: Option<String>,
+    /// e.g. `TCP`, `HTTP` or `CUSTOM`.
+    #[serde(rename = "CheckType", default, skip_serializing_if = "Option::is_none")]
+    pub check_type: Option<String>,
+}
+
+flat_field!(HealthCheck {
+    health_switch => "HealthSwitch",
+    time_out => "TimeOut",
+    interval_time => "IntervalTime",
+    health_num => "HealthNum",
+    un_health_num => "UnHealthNum",
+    http_code => "HttpCode",
+    http_check_path => "HttpCheckPath",
+    http_check_domain => "HttpCheckDomain",
+    http_check_method => "HttpCheckMethod",
+    check_type => "CheckType",
+});
+
+/// A forwarding rule of a layer 7 listener, as created by `CreateRule`.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RuleInput {
+    #[serde(rename = "Domain")]
+    pub domain: String,
+    #[serde(rename = "Url")]
+    pub url: String,
+    /// In seconds; 0 disables session persistence.
+    #[serde(rename = "SessionExpireTime", skip_serializing_if = "Option::is_none")]
+    pub session_expire_time: Option<i64>,
+    #[serde(rename = "HealthCheck", skip_serializing_if = "Option::is_none")]
+    pub health_check: Option<HealthCheck>,
+    /// `WRR`, `LEAST_CONN` or `IP_HASH`.
+    #[serde(rename = "Scheduler", skip_serializing_if = "Option::is_none")]
+    pub scheduler: Option<String>,
+    /// Protocol between the CLB and the targets, `HTTP` or `HTTPS`.
+    #[serde(rename = "ForwardType", skip_serializing_if = "Option::is_none")]
+    pub forward_type: Option<String>,
+}
+
+impl RuleInput {
+    pub fn new<D: Into<String>, U: Into<String>>(domain: D, url: U) -> RuleInput {
+        RuleInput {
+            domain: domain.into(),
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+}
+
+flat_field!(RuleInput {
+    domain => "Domain",
+    url => "Url",
+    session_expire_time => "SessionExpireTime",
+    health_check => "HealthCheck",
+    scheduler => "Scheduler",
+    forward_type => "ForwardType",
+});
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RuleOutput {
+    #[serde(rename = "LocationId")]
+    pub location_id: String,
+    #[serde(rename = "Domain", default)]
+    pub domain: String,
+    #[serde(rename = "Url", default)]
+    pub url: String,
+    #[serde(rename = "SessionExpireTime", default)]
+    pub session_expire_time: i64,
+    #[serde(rename = "HealthCheck", default)]
+    pub health_check: Option<HealthCheck>,
+    #[serde(rename = "Scheduler", default)]
+    pub scheduler: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Listener {
+    #[serde(rename = "ListenerId")]
+    pub listener_id: String,
+    #[serde(rename = "ListenerName", default)]
+    pub listener_name: String,
+    /// `TCP`, `UDP`, `HTTP`, `HTTPS` or `TCP_SSL`.
+    #[serde(rename = "Protocol", default)]
+    pub protocol: String,
+    #[serde(rename = "Port", default)]
+    pub port: i64,
+    #[serde(rename = "HealthCheck", default)]
+    pub health_check: Option<HealthCheck>,
+    #[serde(rename = "Scheduler", default)]
+    pub scheduler: Option<String>,
+    #[serde(rename = "SessionExpireTime", default)]
+    pub session_expire_time: Option<i64>,
+    /// Forwarding rules of a layer 7 listener.
+    #[serde(rename = "Rules", default)]
+    pub rules: Option<Vec<RuleOutput>>,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: String,
+}
+
+/// A backend of a listener: a CVM instance or, for CLBs bound to an ENI, an IP.
+///
+/// ```ignore
+/// Target::instance("ins-1", 8080).weight(10);
+/// ```
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Target {
+    #[serde(rename = "Port")]
+    pub port: i64,
+    #[serde(rename = "InstanceId", skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "EniIp", skip_serializing_if = "Option::is_none")]
+    pub eni_ip: Option<String>,
+    /// From 0 to 100, 10 by default.
+    #[serde(rename = "Weight", skip_serializing_if = "Option::is_none")]
+    pub weight: Option<i64>,
+}
+
+impl Target {
+    pub fn instance<S: Into<String>>(instance_id: S, port: i64) -> Target {
+        Target {
+            port,
+            instance_id: Some(instance_id.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn eni_ip<S: Into<String>>(eni_ip: S, port: i64) -> Target {
+        Target {
+            port,
+            eni_ip: Some(eni_ip.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn weight(mut self, weight: i64) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+}
+
+flat_field!(Target {
+    port => "Port",
+    instance_id => "InstanceId",
+    eni_ip => "EniIp",
+    weight => "Weight",
+});
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TargetHealth {
+    #[serde(rename = "IP")]
+    pub ip: String,
+    #[serde(rename = "Port")]
+    pub port: i64,
+    #[serde(rename = "HealthStatus")]
+    pub health_status: bool,
+    /// The instance id, or the IP for an ENI target.
+    #[serde(rename = "TargetId", default)]
+    pub target_id: String,
+    /// `Alive`, `Dead`, `Unknown` or `Close`.
+    #[serde(rename = "HealthStatusDetail", default)]
+    pub health_status_detail: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RuleHealth {
+    #[serde(rename = "LocationId")]
+    pub location_id: String,
+    #[serde(rename = "Domain", default)]
+    pub domain: Option<String>,
+    #[serde(rename = "Url", default)]
+    pub url: Option<String>,
+    #[serde(rename = "Targets", default)]
+    pub targets: Vec<TargetHealth>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ListenerHealth {
+    #[serde(rename = "ListenerId")]
+    pub listener_id: String,
+    #[serde(rename = "ListenerName", default)]
+    pub listener_name: Option<String>,
+    #[serde(rename = "Protocol", default)]
+    pub protocol: String,
+    #[serde(rename = "Port", default)]
+    pub port: i64,
+    /// Layer 4 listeners have a single rule without a domain or url.
+    #[serde(rename = "Rules", default)]
+    pub rules: Vec<RuleHealth>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LoadBalancerHealth {
+    #[serde(rename = "LoadBalancerId")]
+    pub load_balancer_id: String,
+    #[serde(rename = "LoadBalancerName", default)]
+    pub load_balancer_name: Option<String>,
+    #[serde(rename = "Listeners", default)]
+    pub listeners: Vec<ListenerHealth>,
+}
+
+impl LoadBalancerHealth {
+    /// Every target of every listener, with the id of its listener.
+    pub fn targets(&self) -> impl Iterator<Item = (&str, &TargetHealth)> {
+        self.listeners.iter().flat_map(|listener| {
+            listener.rules.iter().flat_map(move |rule| {
+                rule.targets
+                    .iter()
+                    .map(move |target| (listener.listener_id.as_str(), target))
+            })
+        })
+    }
+}
+
+/// State of the asynchronous task behind a mutating CLB call.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(from = "i64")]
+pub enum TaskStatus {
+    Succeeded,
+    Failed,
+    Running,
+    Unknown(i64),
+}
+
+impl From<i64> for TaskStatus {
+    fn from(status: i64) -> Self {
+        match status {
+            0 => TaskStatus::Succeeded,
+            1 => TaskStatus::Failed,
+            2 => TaskStatus::Running,
+            status => TaskStatus::Unknown(status),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::{Target, API_VERSION};
+
+request! {
+    /// Sets the weight of targets, either `Weight` for all of them or the
+    /// `Weight` of each target.
+    ModifyTargetWeightRequest, ModifyTargetWeightRequestBuilder => ("clb", "ModifyTargetWeight", API_VERSION) {
+        load_balancer_id: String => "LoadBalancerId",
+        listener_id: String => "ListenerId",
+        location_id: String => "LocationId",
+        domain: String => "Domain",
+        url: String => "Url",
+        targets: Vec<Target> => "Targets",
+        weight: i64 => "Weight",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ModifyTargetWeightResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
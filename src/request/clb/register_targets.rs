@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::{Target, API_VERSION};
+
+request! {
+    /// Binds targets to a layer 4 listener, or to a rule of a layer 7 listener
+    /// given by `LocationId` or by `Domain` and `Url`.
+    RegisterTargetsRequest, RegisterTargetsRequestBuilder => ("clb", "RegisterTargets", API_VERSION) {
+        load_balancer_id: String => "LoadBalancerId",
+        listener_id: String => "ListenerId",
+        targets: Vec<Target> => "Targets",
+        location_id: String => "LocationId",
+        domain: String => "Domain",
+        url: String => "Url",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RegisterTargetsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use super::{Filter, Image, API_VERSION};
+
+request! {
+    DescribeImagesRequest, DescribeImagesRequestBuilder => ("cvm", "DescribeImages", API_VERSION) {
+        image_ids: Vec<String> => "ImageIds",
+        /// e.g. `image-type`, `platform` or `image-name`.
+        filters: Vec<Filter> => "Filters",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+        /// Only images that can run on this type.
+        instance_type: String => "InstanceType",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeImagesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "ImageSet", default)]
+    pub image_set: Vec<Image>,
+    #[serde(rename = "TotalCount")]
+    pub total_count: i64,
+}
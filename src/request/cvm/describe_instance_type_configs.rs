@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::{Filter, InstanceTypeConfig, API_VERSION};
+
+request! {
+    DescribeInstanceTypeConfigsRequest, DescribeInstanceTypeConfigsRequestBuilder => ("cvm", "DescribeInstanceTypeConfigs", API_VERSION) {
+        /// `zone` or `instance-family`.
+        filters: Vec<Filter> => "Filters",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeInstanceTypeConfigsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "InstanceTypeConfigSet", default)]
+    pub instance_type_config_set: Vec<InstanceTypeConfig>,
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+
+use super::{Filter, Instance, InstanceFilter, API_VERSION};
+
+/// Most instances a single `DescribeInstances` call may name or return.
+pub const MAX_DESCRIBE_INSTANCES: usize = 100;
+
+request! {
+    DescribeInstancesRequest, DescribeInstancesRequestBuilder => ("cvm", "DescribeInstances", API_VERSION) {
+        instance_ids: Vec<String> => "InstanceIds",
+        /// Cannot be combined with `InstanceIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: i64 => "Offset",
+        /// At most 100.
+        limit: i64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeInstancesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: i64,
+    #[serde(rename = "InstanceSet", default)]
+    pub instance_set: Vec<Instance>,
+}
+
+impl DescribeInstancesRequestBuilder {
+    /// Adds a filter to the ones already set.
+    pub fn filter<F: Into<Filter>>(mut self, filter: F) -> Self {
+        self.req
+            .filters
+            .get_or_insert_with(Vec::new)
+            .push(filter.into());
+        self
+    }
+}
+
+impl From<InstanceFilter> for DescribeInstancesRequestBuilder {
+    fn from(filter: InstanceFilter) -> Self {
+        DescribeInstancesRequest::builder().filter(filter)
+    }
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::{ZoneInfo, API_VERSION};
+
+request! {
+    DescribeZonesRequest, DescribeZonesRequestBuilder => ("cvm", "DescribeZones", API_VERSION) {}
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeZonesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: i64,
+    #[serde(rename = "ZoneSet", default)]
+    pub zone_set: Vec<ZoneInfo>,
+}
@@ -0,0 +1,25 @@
+pub mod describe_images;
+pub mod describe_instance_type_configs;
+pub mod describe_instances;
+pub mod describe_zones;
+pub mod models;
+pub mod reboot_instances;
+pub mod reset_instance;
+pub mod run_instances;
+pub mod start_instances;
+pub mod stop_instances;
+pub mod terminate_instances;
+
+pub use describe_images::*;
+pub use describe_instance_type_configs::*;
+pub use describe_instances::*;
+pub use describe_zones::*;
+pub use models::*;
+pub use reboot_instances::*;
+pub use reset_instance::*;
+pub use run_instances::*;
+pub use start_instances::*;
+pub use stop_instances::*;
+pub use terminate_instances::*;
+
+pub const API_VERSION: &str = "2017-03-12";
@@ -0,0 +1,395 @@
+use serde::{Deserialize, Serialize};
+use std::{convert::Infallible, str::FromStr};
+
+/// A `Name`/`Values` filter of the Describe actions, e.g. `zone` or
+/// `instance-state`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Values")]
+    pub values: Vec<String>,
+}
+
+impl Filter {
+    pub fn new<N, I, V>(name: N, values: I) -> Filter
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        Filter {
+            name: name.into(),
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+flat_field!(Filter {
+    name => "Name",
+    values => "Values",
+});
+
+/// The filters `DescribeInstances` accepts.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstanceFilter {
+    Zone(String),
+    ProjectId(i64),
+    VpcId(String),
+    SubnetId(String),
+    InstanceId(String),
+    InstanceName(String),
+    InstanceState(InstanceState),
+    InstanceType(String),
+    InstanceChargeType(String),
+    PrivateIpAddress(String),
+    PublicIpAddress(String),
+    SecurityGroupId(String),
+    /// Instances having the tag, whatever its value.
+    TagKey(String),
+    Tag {
+        key: String,
+        value: String,
+    },
+}
+
+impl From<InstanceFilter> for Filter {
+    fn from(filter: InstanceFilter) -> Self {
+        match filter {
+            InstanceFilter::Zone(zone) => Filter::new("zone", vec![zone]),
+            InstanceFilter::ProjectId(id) => Filter::new("project-id", vec![id.to_string()]),
+            InstanceFilter::VpcId(id) => Filter::new("vpc-id", vec![id]),
+            InstanceFilter::SubnetId(id) => Filter::new("subnet-id", vec![id]),
+            InstanceFilter::InstanceId(id) => Filter::new("instance-id", vec![id]),
+            InstanceFilter::InstanceName(name) => Filter::new("instance-name", vec![name]),
+            InstanceFilter::InstanceState(state) => {
+                Filter::new("instance-state", vec![state.as_ref()])
+            }
+            InstanceFilter::InstanceType(ty) => Filter::new("instance-type", vec![ty]),
+            InstanceFilter::InstanceChargeType(ty) => Filter::new("instance-charge-type", vec![ty]),
+            InstanceFilter::PrivateIpAddress(ip) => Filter::new("private-ip-address", vec![ip]),
+            InstanceFilter::PublicIpAddress(ip) => Filter::new("public-ip-address", vec![ip]),
+            InstanceFilter::SecurityGroupId(id) => Filter::new("security-group-id", vec![id]),
+            InstanceFilter::TagKey(key) => Filter::new("tag-key", vec![key]),
+            InstanceFilter::Tag { key, value } => Filter::new(format!("tag:{}", key), vec![value]),
+        }
+    }
+}
+
+/// Lifecycle state of an instance.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstanceState {
+    Pending,
+    LaunchFailed,
+    Running,
+    Stopped,
+    Starting,
+    Stopping,
+    Rebooting,
+    Shutdown,
+    Terminating,
+    Unknown(String),
+}
+
+impl AsRef<str> for InstanceState {
+    fn as_ref(&self) -> &str {
+        match self {
+            InstanceState::Pending => "PENDING",
+            InstanceState::LaunchFailed => "LAUNCH_FAILED",
+            InstanceState::Running => "RUNNING",
+            InstanceState::Stopped => "STOPPED",
+            InstanceState::Starting => "STARTING",
+            InstanceState::Stopping => "STOPPING",
+            InstanceState::Rebooting => "REBOOTING",
+            InstanceState::Shutdown => "SHUTDOWN",
+            InstanceState::Terminating => "TERMINATING",
+            InstanceState::Unknown(s) => s,
+        }
+    }
+}
+
+impl FromStr for InstanceState {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "PENDING" => InstanceState::Pending,
+            "LAUNCH_FAILED" => InstanceState::LaunchFailed,
+            "RUNNING" => InstanceState::Running,
+            "STOPPED" => InstanceState::Stopped,
+            "STARTING" => InstanceState::Starting,
+            "STOPPING" => InstanceState::Stopping,
+            "REBOOTING" => InstanceState::Rebooting,
+            "SHUTDOWN" => InstanceState::Shutdown,
+            "TERMINATING" => InstanceState::Terminating,
+            s => InstanceState::Unknown(s.to_string()),
+        })
+    }
+}
+
+serde_str!(InstanceState);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Placement {
+    #[serde(rename = "Zone")]
+    pub zone: String,
+    #[serde(rename = "ProjectId", default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<i64>,
+}
+
+flat_field!(Placement {
+    zone => "Zone",
+    project_id => "ProjectId",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SystemDisk {
+    /// e.g. `CLOUD_PREMIUM` or `CLOUD_SSD`.
+    #[serde(rename = "DiskType", default, skip_serializing_if = "Option::is_none")]
+    pub disk_type: Option<String>,
+    #[serde(rename = "DiskId", default, skip_serializing_if = "Option::is_none")]
+    pub disk_id: Option<String>,
+    /// In GB.
+    #[serde(rename = "DiskSize", default, skip_serializing_if = "Option::is_none")]
+    pub disk_size: Option<i64>,
+}
+
+flat_field!(SystemDisk {
+    disk_type => "DiskType",
+    disk_id => "DiskId",
+    disk_size => "DiskSize",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct DataDisk {
+    /// In GB.
+    #[serde(rename = "DiskSize")]
+    pub disk_size: i64,
+    #[serde(rename = "DiskType", default, skip_serializing_if = "Option::is_none")]
+    pub disk_type: Option<String>,
+    #[serde(rename = "DiskId", default, skip_serializing_if = "Option::is_none")]
+    pub disk_id: Option<String>,
+    #[serde(
+        rename = "DeleteWithInstance",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub delete_with_instance: Option<bool>,
+}
+
+flat_field!(DataDisk {
+    disk_size => "DiskSize",
+    disk_type => "DiskType",
+    disk_id => "DiskId",
+    delete_with_instance => "DeleteWithInstance",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct VirtualPrivateCloud {
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+    #[serde(
+        rename = "PrivateIpAddresses",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub private_ip_addresses: Vec<String>,
+}
+
+flat_field!(VirtualPrivateCloud {
+    vpc_id => "VpcId",
+    subnet_id => "SubnetId",
+    private_ip_addresses => "PrivateIpAddresses",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct InternetAccessible {
+    /// e.g. `TRAFFIC_POSTPAID_BY_HOUR`.
+    #[serde(
+        rename = "InternetChargeType",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub internet_charge_type: Option<String>,
+    /// In Mbps.
+    #[serde(
+        rename = "InternetMaxBandwidthOut",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub internet_max_bandwidth_out: Option<i64>,
+    #[serde(
+        rename = "PublicIpAssigned",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub public_ip_assigned: Option<bool>,
+}
+
+flat_field!(InternetAccessible {
+    internet_charge_type => "InternetChargeType",
+    internet_max_bandwidth_out => "InternetMaxBandwidthOut",
+    public_ip_assigned => "PublicIpAssigned",
+});
+
+/// How to log into a new instance: a password, key pairs, or the settings of
+/// the image.
+#[derive(Serialize, Default, Clone, PartialEq)]
+pub struct LoginSettings {
+    #[serde(rename = "Password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(rename = "KeyIds", skip_serializing_if = "Vec::is_empty")]
+    pub key_ids: Vec<String>,
+    /// `TRUE` to keep the settings of the image.
+    #[serde(rename = "KeepImageLogin", skip_serializing_if = "Option::is_none")]
+    pub keep_image_login: Option<String>,
+}
+
+impl std::fmt::Debug for LoginSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginSettings")
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .field("key_ids", &self.key_ids)
+            .field("keep_image_login", &self.keep_image_login)
+            .finish()
+    }
+}
+
+flat_field!(LoginSettings {
+    password => "Password",
+    key_ids => "KeyIds",
+    keep_image_login => "KeepImageLogin",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Tag {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+flat_field!(Tag {
+    key => "Key",
+    value => "Value",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TagSpecification {
+    /// `instance`.
+    #[serde(rename = "ResourceType")]
+    pub resource_type: String,
+    #[serde(rename = "Tags")]
+    pub tags: Vec<Tag>,
+}
+
+flat_field!(TagSpecification {
+    resource_type => "ResourceType",
+    tags => "Tags",
+});
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Instance {
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+    #[serde(rename = "InstanceName", default)]
+    pub instance_name: String,
+    #[serde(rename = "InstanceType", default)]
+    pub instance_type: String,
+    #[serde(rename = "InstanceState")]
+    pub instance_state: InstanceState,
+    #[serde(rename = "CPU", default)]
+    pub cpu: i64,
+    /// In GB.
+    #[serde(rename = "Memory", default)]
+    pub memory: i64,
+    #[serde(rename = "Placement", default)]
+    pub placement: Placement,
+    #[serde(rename = "ImageId", default)]
+    pub image_id: String,
+    #[serde(rename = "OsName", default)]
+    pub os_name: String,
+    #[serde(rename = "PrivateIpAddresses", default)]
+    pub private_ip_addresses: Vec<String>,
+    #[serde(rename = "PublicIpAddresses", default)]
+    pub public_ip_addresses: Option<Vec<String>>,
+    #[serde(rename = "SystemDisk", default)]
+    pub system_disk: SystemDisk,
+    #[serde(rename = "DataDisks", default)]
+    pub data_disks: Option<Vec<DataDisk>>,
+    #[serde(rename = "VirtualPrivateCloud", default)]
+    pub virtual_private_cloud: VirtualPrivateCloud,
+    #[serde(rename = "InstanceChargeType", default)]
+    pub instance_charge_type: String,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "ExpiredTime", default)]
+    pub expired_time: Option<String>,
+    #[serde(rename = "Tags", default)]
+    pub tags: Vec<Tag>,
+    #[serde(rename = "LatestOperation", default)]
+    pub latest_operation: Option<String>,
+    /// `OPERATING`, `SUCCESS` or `FAILED`.
+    #[serde(rename = "LatestOperationState", default)]
+    pub latest_operation_state: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Image {
+    #[serde(rename = "ImageId")]
+    pub image_id: String,
+    #[serde(rename = "OsName", default)]
+    pub os_name: String,
+    /// `PUBLIC_IMAGE`, `PRIVATE_IMAGE` or `SHARED_IMAGE`.
+    #[serde(rename = "ImageType", default)]
+    pub image_type: String,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "ImageName", default)]
+    pub image_name: String,
+    #[serde(rename = "ImageDescription", default)]
+    pub image_description: String,
+    /// In GB.
+    #[serde(rename = "ImageSize", default)]
+    pub image_size: i64,
+    #[serde(rename = "Architecture", default)]
+    pub architecture: String,
+    /// `NORMAL`, `CREATING`, ...
+    #[serde(rename = "ImageState", default)]
+    pub image_state: String,
+    #[serde(rename = "Platform", default)]
+    pub platform: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct InstanceTypeConfig {
+    #[serde(rename = "Zone")]
+    pub zone: String,
+    #[serde(rename = "InstanceType")]
+    pub instance_type: String,
+    #[serde(rename = "InstanceFamily", default)]
+    pub instance_family: String,
+    #[serde(rename = "GPU", default)]
+    pub gpu: i64,
+    #[serde(rename = "CPU", default)]
+    pub cpu: i64,
+    #[serde(rename = "Memory", default)]
+    pub memory: i64,
+    #[serde(rename = "FPGA", default)]
+    pub fpga: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ZoneInfo {
+    #[serde(rename = "Zone")]
+    pub zone: String,
+    #[serde(rename = "ZoneName", default)]
+    pub zone_name: String,
+    #[serde(rename = "ZoneId", default)]
+    pub zone_id: String,
+    /// `AVAILABLE` or `UNAVAILABLE`.
+    #[serde(rename = "ZoneState", default)]
+    pub zone_state: String,
+}
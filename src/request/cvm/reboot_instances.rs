@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    RebootInstancesRequest, RebootInstancesRequestBuilder => ("cvm", "RebootInstances", API_VERSION) {
+        instance_ids: Vec<String> => "InstanceIds",
+        /// `SOFT`, `HARD` or `SOFT_FIRST` (default).
+        stop_type: String => "StopType",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RebootInstancesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
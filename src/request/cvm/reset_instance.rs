@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{LoginSettings, SystemDisk, API_VERSION};
+
+request! {
+    /// Reinstalls the system disk of an instance, from another image if `ImageId` is set.
+    ResetInstanceRequest, ResetInstanceRequestBuilder => ("cvm", "ResetInstance", API_VERSION) {
+        instance_id: String => "InstanceId",
+        image_id: String => "ImageId",
+        system_disk: SystemDisk => "SystemDisk",
+        login_settings: LoginSettings => "LoginSettings",
+        host_name: String => "HostName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ResetInstanceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
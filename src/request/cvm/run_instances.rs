@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+use super::{
+    DataDisk, InternetAccessible, LoginSettings, Placement, SystemDisk, TagSpecification,
+    VirtualPrivateCloud, API_VERSION,
+};
+
+request! {
+    /// Creates instances. They are `PENDING` at first, see
+    /// `CVMClient::wait_instances`.
+    RunInstancesRequest, RunInstancesRequestBuilder => ("cvm", "RunInstances", API_VERSION) {
+        /// e.g. `POSTPAID_BY_HOUR`, `PREPAID` or `SPOTPAID`.
+        instance_charge_type: String => "InstanceChargeType",
+        placement: Placement => "Placement",
+        instance_type: String => "InstanceType",
+        image_id: String => "ImageId",
+        system_disk: SystemDisk => "SystemDisk",
+        data_disks: Vec<DataDisk> => "DataDisks",
+        virtual_private_cloud: VirtualPrivateCloud => "VirtualPrivateCloud",
+        internet_accessible: InternetAccessible => "InternetAccessible",
+        instance_count: i64 => "InstanceCount",
+        instance_name: String => "InstanceName",
+        login_settings: LoginSettings => "LoginSettings",
+        security_group_ids: Vec<String> => "SecurityGroupIds",
+        /// Makes retries idempotent.
+        client_token: String => "ClientToken",
+        host_name: String => "HostName",
+        tag_specification: Vec<TagSpecification> => "TagSpecification",
+        dry_run: bool => "DryRun",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RunInstancesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "InstanceIdSet", default)]
+    pub instance_id_set: Vec<String>,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    StartInstancesRequest, StartInstancesRequestBuilder => ("cvm", "StartInstances", API_VERSION) {
+        instance_ids: Vec<String> => "InstanceIds",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StartInstancesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    StopInstancesRequest, StopInstancesRequestBuilder => ("cvm", "StopInstances", API_VERSION) {
+        instance_ids: Vec<String> => "InstanceIds",
+        /// `SOFT`, `HARD` or `SOFT_FIRST` (default).
+        stop_type: String => "StopType",
+        /// `KEEP_CHARGING` or `STOP_CHARGING`.
+        stopped_mode: String => "StoppedMode",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StopInstancesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
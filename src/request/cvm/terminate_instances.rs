@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    TerminateInstancesRequest, TerminateInstancesRequestBuilder => ("cvm", "TerminateInstances", API_VERSION) {
+        instance_ids: Vec<String> => "InstanceIds",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TerminateInstancesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
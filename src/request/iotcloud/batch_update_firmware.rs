@@ -1,9 +1,10 @@
+use md5::{Digest, Md5};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::{collections::HashMap, io::Read, path::Path, time::Duration};
 
 use serde::ser::SerializeStruct;
 
-use crate::{client::Configuration, Flat, IntoRequest};
+use crate::{client::Configuration, Error, Flat, IntoRequest};
 
 use super::{RequestBuilder, ServiceRequest, API_VERSION};
 
@@ -16,7 +17,7 @@ pub struct BatchUpdateFirmwareRequest {
     // #[serde(rename = "FirmwareOriVersion")]
     pub firmware_ori_version: Option<String>,
     // #[serde(rename = "UpgradeMethod")]
-    pub upgrade_method: Option<u64>,
+    pub upgrade_method: Option<UpgradeMethod>,
     // #[serde(rename = "FileName")]
     pub file_name: Option<String>,
     // #[serde(rename = "FileMd5")]
@@ -26,11 +27,50 @@ pub struct BatchUpdateFirmwareRequest {
     // #[serde(rename = "DeviceNames")]
     pub device_names: Option<Vec<String>>,
     // #[serde(rename = "TimeoutInterval")]
-    pub timeout_interval: Option<u64>,
+    pub timeout_interval: Option<Duration>,
 }
 
-#[derive(Deserialize)]
-pub struct BatchUpdateFirmwareResponse {}
+/// Most devices a single `BatchUpdateFirmware` call may target.
+pub const MAX_FIRMWARE_DEVICE_NAMES: usize = 100;
+/// Longest device name accepted by IoT Hub.
+pub const MAX_DEVICE_NAME_LEN: usize = 48;
+/// Range accepted for `TimeoutInterval`.
+pub const FIRMWARE_TIMEOUT_RANGE: std::ops::RangeInclusive<Duration> =
+    Duration::from_secs(60)..=Duration::from_secs(86400);
+
+/// How devices apply the new firmware, silently when not set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpgradeMethod {
+    /// `0`: the device upgrades without asking the user.
+    Silent,
+    /// `1`: the device upgrades once the user confirms it.
+    UserConfirmed,
+}
+
+impl From<UpgradeMethod> for u64 {
+    fn from(method: UpgradeMethod) -> Self {
+        match method {
+            UpgradeMethod::Silent => 0,
+            UpgradeMethod::UserConfirmed => 1,
+        }
+    }
+}
+
+flat_field!(UpgradeMethod as u64);
+
+impl std::fmt::Display for UpgradeMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u64::from(*self))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BatchUpdateFirmwareResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: u64,
+}
 
 impl serde::Serialize for BatchUpdateFirmwareRequest {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -48,8 +88,8 @@ impl serde::Serialize for BatchUpdateFirmwareRequest {
         if let Some(ref firmware_ori_version) = self.firmware_ori_version {
             state.serialize_field("FirmwareOriVersion", firmware_ori_version)?;
         }
-        if let Some(ref upgrade_method) = self.upgrade_method {
-            state.serialize_field("UpgradeMethod", upgrade_method)?;
+        if let Some(upgrade_method) = self.upgrade_method {
+            state.serialize_field("UpgradeMethod", &u64::from(upgrade_method))?;
         }
         if let Some(ref file_name) = self.file_name {
             state.serialize_field("FileName", file_name)?;
@@ -66,7 +106,7 @@ impl serde::Serialize for BatchUpdateFirmwareRequest {
             }
         }
         if let Some(ref timeout_interval) = self.timeout_interval {
-            state.serialize_field("TimeoutInterval", timeout_interval)?;
+            state.serialize_field("TimeoutInterval", &timeout_interval.as_secs())?;
         }
         state.end()
     }
@@ -80,6 +120,46 @@ impl ServiceRequest for BatchUpdateFirmwareRequest {
     fn action(&self) -> &'static str {
         "BatchUpdateFirmware"
     }
+
+    fn validate(&self) -> crate::Result<()> {
+        let invalid = |message: String| Err(Error::InvalidRequest(message));
+        if self.product_id.as_deref().unwrap_or_default().is_empty() {
+            return invalid("BatchUpdateFirmware: ProductID is required".to_string());
+        }
+        if self
+            .firmware_version
+            .as_deref()
+            .unwrap_or_default()
+            .is_empty()
+        {
+            return invalid("BatchUpdateFirmware: FirmwareVersion is required".to_string());
+        }
+        let device_names = self.device_names.as_deref().unwrap_or_default();
+        if device_names.len() > MAX_FIRMWARE_DEVICE_NAMES {
+            return invalid(format!(
+                "BatchUpdateFirmware: {} DeviceNames given, at most {} are allowed",
+                device_names.len(),
+                MAX_FIRMWARE_DEVICE_NAMES
+            ));
+        }
+        if let Some(name) = device_names.iter().find(|name| !is_valid_device_name(name)) {
+            return invalid(format!(
+                "BatchUpdateFirmware: invalid device name {:?}, expected 1 to {} letters, digits, ':', '_' or '-'",
+                name, MAX_DEVICE_NAME_LEN
+            ));
+        }
+        if let Some(timeout) = self.timeout_interval {
+            if !FIRMWARE_TIMEOUT_RANGE.contains(&timeout) {
+                return invalid(format!(
+                    "BatchUpdateFirmware: TimeoutInterval of {}s is outside {}s..={}s",
+                    timeout.as_secs(),
+                    FIRMWARE_TIMEOUT_RANGE.start().as_secs(),
+                    FIRMWARE_TIMEOUT_RANGE.end().as_secs()
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Default)]
@@ -100,7 +180,7 @@ impl BatchUpdateFirmwareRequestBuilder {
         self.request.firmware_ori_version = Some(firmware_ori_version);
         self
     }
-    pub fn set_upgrade_method(mut self, upgrade_method: u64) -> Self {
+    pub fn set_upgrade_method(mut self, upgrade_method: UpgradeMethod) -> Self {
         self.request.upgrade_method = Some(upgrade_method);
         self
     }
@@ -120,24 +200,75 @@ impl BatchUpdateFirmwareRequestBuilder {
         self.request.device_names = Some(device_names);
         self
     }
-    pub fn set_timeout_interval(mut self, timeout_interval: u64) -> Self {
+    pub fn set_timeout_interval(mut self, timeout_interval: Duration) -> Self {
         self.request.timeout_interval = Some(timeout_interval);
         self
     }
 }
 
+impl BatchUpdateFirmwareRequestBuilder {
+    /// Sets `FileMd5` and `FileSize` from the firmware file at `path`, and
+    /// `FileName` from its file name unless already set.
+    pub fn set_firmware_file<P: AsRef<Path>>(mut self, path: P) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let (md5, size) = file_digest(path)?;
+        if self.request.file_name.is_none() {
+            self.request.file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+        }
+        Ok(self.set_file_md5(md5).set_file_size(size))
+    }
+
+    /// Checks the request before it is sent: `ProductID` and
+    /// `FirmwareVersion` are required, device names must be valid and at most
+    /// `MAX_FIRMWARE_DEVICE_NAMES`, and the timeout within
+    /// `FIRMWARE_TIMEOUT_RANGE`. `send` runs the same checks on a builder
+    /// passed as is.
+    pub fn build(self) -> crate::Result<BatchUpdateFirmwareRequest> {
+        self.request.validate()?;
+        Ok(self.request)
+    }
+}
+
+pub(super) fn is_valid_device_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_DEVICE_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '_' | '-'))
+}
+
+/// Hex encoded md5 and size in bytes of the file at `path`.
+pub(crate) fn file_digest(path: &Path) -> std::io::Result<(String, u64)> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Md5::new();
+    let mut buf = vec![0; 64 * 1024];
+    let mut size = 0;
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        size += n as u64;
+    }
+    Ok((hex::encode(hasher.finalize()), size))
+}
+
 impl IntoRequest for BatchUpdateFirmwareRequestBuilder {
     type Request = BatchUpdateFirmwareRequest;
 
     fn into_request(self, config: Configuration) -> RequestBuilder<Self::Request> {
-        RequestBuilder {
-            credential: config.credential.into(),
-            profile: config.profile.into(),
-            region: config.region,
-            version: Some(API_VERSION.to_string()),
-            inner: self.request,
-            ..Default::default()
-        }
+        RequestBuilder::from_config(config, API_VERSION, self.request)
+    }
+}
+
+impl IntoRequest for BatchUpdateFirmwareRequest {
+    type Request = BatchUpdateFirmwareRequest;
+
+    fn into_request(self, config: Configuration) -> RequestBuilder<Self::Request> {
+        BatchUpdateFirmwareRequestBuilder { request: self }.into_request(config)
     }
 }
 
@@ -159,7 +290,11 @@ impl Flat for BatchUpdateFirmwareRequest {
         Self::insert(&mut hm, "FileName", &self.file_name);
         Self::insert(&mut hm, "FileMd5", &self.file_md5);
         Self::insert(&mut hm, "FileSize", &self.file_size);
-        Self::insert(&mut hm, "TimeoutInterval", &self.timeout_interval);
+        Self::insert(
+            &mut hm,
+            "TimeoutInterval",
+            &self.timeout_interval.map(|timeout| timeout.as_secs()),
+        );
         Self::insert_slice(&mut hm, "DeviceNames", &self.device_names);
         hm
     }
@@ -199,13 +334,81 @@ mod test {
             product_id: "product_id".to_string().into(),
             firmware_version: "firmware_version".to_string().into(),
             firmware_ori_version: "firmware_ori_version".to_string().into(),
-            upgrade_method: 1.into(),
+            upgrade_method: Some(super::UpgradeMethod::Silent),
             file_name: "file_name".to_string().into(),
             file_md5: "file_md5".to_string().into(),
             file_size: 144.into(),
             device_names: vec!["device0".to_string(), "device1".to_string()].into(),
-            timeout_interval: 20.into(),
+            timeout_interval: Some(std::time::Duration::from_secs(120)),
         };
-        println!("{}", serde_json::to_string(&req).unwrap());
+        assert_eq!(
+            serde_json::to_string(&req).unwrap(),
+            r#"{"ProductID":"product_id","FirmwareVersion":"firmware_version","FirmwareOriVersion":"firmware_ori_version","UpgradeMethod":0,"FileName":"file_name","FileMd5":"file_md5","FileSize":144,"DeviceNames":["device0","device1"],"TimeoutInterval":120}"#
+        );
+    }
+
+    #[test]
+    fn test_set_firmware_file() {
+        let path = std::env::temp_dir().join(format!("firmware-{}.bin", std::process::id()));
+        std::fs::write(&path, b"hello firmware").unwrap();
+        let req = super::BatchUpdateFirmwareRequest::builder()
+            .set_firmware_file(&path)
+            .unwrap()
+            .request;
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            req.file_md5.as_deref(),
+            Some("5d99b396caab4bfae4e25207b62b30e2")
+        );
+        assert_eq!(req.file_size, Some(14));
+        assert_eq!(
+            req.file_name,
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        );
+    }
+
+    #[test]
+    fn test_build() {
+        use std::time::Duration;
+
+        use super::{BatchUpdateFirmwareRequest, UpgradeMethod};
+
+        let builder = || {
+            BatchUpdateFirmwareRequest::builder()
+                .set_product_id("ABCDEFGHIJ".to_string())
+                .set_firmware_version("1.0.1".to_string())
+        };
+        let req = builder()
+            .set_upgrade_method(UpgradeMethod::UserConfirmed)
+            .set_timeout_interval(Duration::from_secs(600))
+            .set_device_names(vec!["lamp-1".to_string()])
+            .build()
+            .unwrap();
+        assert_eq!(req.upgrade_method, Some(UpgradeMethod::UserConfirmed));
+
+        let err = BatchUpdateFirmwareRequest::builder()
+            .set_firmware_version("1.0.1".to_string())
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid request: BatchUpdateFirmware: ProductID is required"
+        );
+        let err = builder()
+            .set_device_names((0..101).map(|i| format!("lamp-{}", i)).collect())
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("101 DeviceNames given"));
+        let err = builder()
+            .set_device_names(vec!["lamp 1".to_string()])
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains(r#"invalid device name "lamp 1""#));
+        let err = builder()
+            .set_timeout_interval(Duration::from_secs(5))
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("TimeoutInterval of 5s"));
     }
 }
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Cancels the update of one device within a firmware task.
+    ///
+    /// IoT Hub has no `CancelUpdateFirmwareTask` action: a task is cancelled
+    /// by cancelling each of its devices, as listed by
+    /// `DescribeFirmwareTaskDevices`.
+    CancelDeviceFirmwareTaskRequest, CancelDeviceFirmwareTaskRequestBuilder => ("iotcloud", "CancelDeviceFirmwareTask", API_VERSION) {
+        product_id: String => "ProductID",
+        device_name: String => "DeviceName",
+        firmware_version: String => "FirmwareVersion",
+        task_id: u64 => "TaskId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CancelDeviceFirmwareTaskResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
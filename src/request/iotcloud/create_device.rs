@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use super::{Attribute, API_VERSION};
+
+request! {
+    CreateDeviceRequest, CreateDeviceRequestBuilder => ("iotcloud", "CreateDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        attribute: Attribute => "Attribute",
+        defined_psk: String => "DefinedPsk",
+        isp: u64 => "Isp",
+        imei: String => "Imei",
+        lora_dev_eui: String => "LoraDevEui",
+        lora_mote_type: u64 => "LoraMoteType",
+        skey: String => "Skey",
+        lora_app_key: String => "LoraAppKey",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    #[serde(rename = "DevicePsk", default)]
+    pub device_psk: String,
+    #[serde(rename = "DeviceCert", default)]
+    pub device_cert: String,
+    #[serde(rename = "DevicePrivateKey", default)]
+    pub device_private_key: String,
+    #[serde(rename = "LoraDevEui", default)]
+    pub lora_dev_eui: String,
+    #[serde(rename = "LoraMoteType", default)]
+    pub lora_mote_type: u64,
+    #[serde(rename = "LoraAppKey", default)]
+    pub lora_app_key: String,
+    #[serde(rename = "LoraNwkKey", default)]
+    pub lora_nwk_key: String,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Starts creating devices by name. Poll `DescribeMultiDevTask` with the
+    /// returned `TaskId`, then read the credentials with `DescribeMultiDevices`.
+    CreateMultiDeviceRequest, CreateMultiDeviceRequestBuilder => ("iotcloud", "CreateMultiDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_names: Vec<String> => "DeviceNames",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateMultiDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: String,
+}
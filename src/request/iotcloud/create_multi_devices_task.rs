@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Creates devices listed in a file uploaded to COS.
+    CreateMultiDevicesTaskRequest, CreateMultiDevicesTaskRequestBuilder => ("iotcloud", "CreateMultiDevicesTask", API_VERSION) {
+        product_id: String => "ProductId",
+        /// `cosfile` or `random`.
+        parameters_type: String => "ParametersType",
+        /// JSON, e.g. `{"FileName":"devices.csv","FileSize":1024,"BatchCount":100}`.
+        parameters: String => "Parameters",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateMultiDevicesTaskResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Id")]
+    pub id: u64,
+}
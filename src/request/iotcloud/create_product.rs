@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::{ProductProperties, ProductPropertiesInput, API_VERSION};
+
+request! {
+    CreateProductRequest, CreateProductRequestBuilder => ("iotcloud", "CreateProduct", API_VERSION) {
+        product_name: String => "ProductName",
+        product_properties: ProductPropertiesInput => "ProductProperties",
+        skey: String => "Skey",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "ProductName")]
+    pub product_name: String,
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    #[serde(rename = "ProductProperties")]
+    pub product_properties: ProductProperties,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::{TopicPrivilege, API_VERSION};
+
+request! {
+    CreateTopicPolicyRequest, CreateTopicPolicyRequestBuilder => ("iotcloud", "CreateTopicPolicy", API_VERSION) {
+        product_id: String => "ProductID",
+        topic_name: String => "TopicName",
+        privilege: TopicPrivilege => "Privilege",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateTopicPolicyResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::{TopicRulePayload, API_VERSION};
+
+request! {
+    CreateTopicRuleRequest, CreateTopicRuleRequestBuilder => ("iotcloud", "CreateTopicRule", API_VERSION) {
+        rule_name: String => "RuleName",
+        topic_rule_payload: TopicRulePayload => "TopicRulePayload",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateTopicRuleResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
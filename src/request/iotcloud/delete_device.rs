@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteDeviceRequest, DeleteDeviceRequestBuilder => ("iotcloud", "DeleteDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        skey: String => "Skey",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
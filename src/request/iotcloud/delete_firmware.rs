@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteFirmwareRequest, DeleteFirmwareRequestBuilder => ("iotcloud", "DeleteFirmware", API_VERSION) {
+        product_id: String => "ProductID",
+        firmware_version: String => "FirmwareVersion",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteFirmwareResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
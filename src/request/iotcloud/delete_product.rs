@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Deletes a product, which must not have any device left.
+    DeleteProductRequest, DeleteProductRequestBuilder => ("iotcloud", "DeleteProduct", API_VERSION) {
+        product_id: String => "ProductId",
+        skey: String => "Skey",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteTopicPolicyRequest, DeleteTopicPolicyRequestBuilder => ("iotcloud", "DeleteTopicPolicy", API_VERSION) {
+        product_id: String => "ProductID",
+        topic_name: String => "TopicName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteTopicPolicyResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
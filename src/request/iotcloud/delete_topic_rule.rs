@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteTopicRuleRequest, DeleteTopicRuleRequestBuilder => ("iotcloud", "DeleteTopicRule", API_VERSION) {
+        rule_name: String => "RuleName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteTopicRuleResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::{DeviceInfo, API_VERSION};
+
+request! {
+    DescribeDeviceRequest, DescribeDeviceRequestBuilder => ("iotcloud", "DescribeDevice", API_VERSION) {
+        product_id: String => "ProductID",
+        device_name: String => "DeviceName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(flatten)]
+    pub device: DeviceInfo,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DescribeDeviceClientKeyRequest, DescribeDeviceClientKeyRequestBuilder => ("iotcloud", "DescribeDeviceClientKey", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceClientKeyResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// Private key of the device certificate.
+    #[serde(rename = "ClientKey")]
+    pub client_key: String,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::{DeviceResourceInfo, API_VERSION};
+
+request! {
+    DescribeDeviceResourceRequest, DescribeDeviceResourceRequestBuilder => ("iotcloud", "DescribeDeviceResource", API_VERSION) {
+        device_name: String => "DeviceName",
+        product_id: String => "ProductID",
+        name: String => "Name",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceResourceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Result")]
+    pub result: DeviceResourceInfo,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use super::{DeviceResourceInfo, API_VERSION};
+
+request! {
+    DescribeDeviceResourcesRequest, DescribeDeviceResourcesRequestBuilder => ("iotcloud", "DescribeDeviceResources", API_VERSION) {
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+        product_id: String => "ProductID",
+        device_name: String => "DeviceName",
+        start_time: u64 => "StartTime",
+        end_time: u64 => "EndTime",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceResourcesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<DeviceResourceInfo>,
+}
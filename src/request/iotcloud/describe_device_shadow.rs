@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DescribeDeviceShadowRequest, DescribeDeviceShadowRequestBuilder => ("iotcloud", "DescribeDeviceShadow", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceShadowResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// The shadow document as a JSON string.
+    #[serde(rename = "Data")]
+    pub data: String,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use super::{DeviceInfo, API_VERSION};
+
+request! {
+    DescribeDevicesRequest, DescribeDevicesRequestBuilder => ("iotcloud", "DescribeDevices", API_VERSION) {
+        product_id: String => "ProductId",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+        firmware_version: String => "FirmwareVersion",
+        device_name: String => "DeviceName",
+        enable_state: u64 => "EnableState",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDevicesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: usize,
+    #[serde(rename = "Devices")]
+    pub devices: Vec<DeviceInfo>,
+}
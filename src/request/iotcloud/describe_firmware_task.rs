@@ -0,0 +1,56 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DescribeFirmwareTaskRequest, DescribeFirmwareTaskRequestBuilder => ("iotcloud", "DescribeFirmwareTask", API_VERSION) {
+        product_id: String => "ProductId",
+        firmware_version: String => "FirmwareVersion",
+        task_id: u64 => "TaskId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeFirmwareTaskResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: u64,
+    #[serde(rename = "Status")]
+    pub status: FirmwareTaskStatus,
+    #[serde(rename = "CreateTime")]
+    pub create_time: i64,
+    #[serde(rename = "Type")]
+    pub task_type: i64,
+    #[serde(rename = "ProductName")]
+    pub product_name: String,
+    #[serde(rename = "UpgradeMode")]
+    pub upgrade_mode: i64,
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    #[serde(rename = "OriginalVersion")]
+    pub original_version: String,
+}
+
+/// State of a firmware update task as reported by `DescribeFirmwareTask`.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(from = "i64")]
+pub enum FirmwareTaskStatus {
+    Waiting,
+    Running,
+    Finished,
+    Cancelled,
+    Unknown(i64),
+}
+
+impl From<i64> for FirmwareTaskStatus {
+    fn from(status: i64) -> Self {
+        match status {
+            0 => FirmwareTaskStatus::Waiting,
+            1 => FirmwareTaskStatus::Running,
+            2 => FirmwareTaskStatus::Finished,
+            3 => FirmwareTaskStatus::Cancelled,
+            status => FirmwareTaskStatus::Unknown(status),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+
+use super::{SearchKeyword, API_VERSION};
+
+request! {
+    DescribeFirmwareTaskDevicesRequest, DescribeFirmwareTaskDevicesRequestBuilder => ("iotcloud", "DescribeFirmwareTaskDevices", API_VERSION) {
+        product_id: String => "ProductId",
+        firmware_version: String => "FirmwareVersion",
+        filters: Vec<SearchKeyword> => "Filters",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeFirmwareTaskDevicesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Total")]
+    pub total: u64,
+    #[serde(rename = "Devices", default)]
+    pub devices: Vec<DeviceUpdateStatus>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeviceUpdateStatus {
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    #[serde(rename = "LastProcessTime", default)]
+    pub last_process_time: u64,
+    /// 0 waiting, 1 downloading, 2 burning, 3 failed, 4 succeeded, 5 cancelled.
+    #[serde(rename = "Status")]
+    pub status: u64,
+    #[serde(rename = "ErrMsg", default)]
+    pub err_msg: String,
+    #[serde(rename = "Retcode", default)]
+    pub retcode: i64,
+    #[serde(rename = "DstVersion", default)]
+    pub dst_version: String,
+    #[serde(rename = "Percent", default)]
+    pub percent: u64,
+    #[serde(rename = "OriVersion", default)]
+    pub ori_version: String,
+    #[serde(rename = "TaskId", default)]
+    pub task_id: u64,
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DescribeFirmwareTaskStatisticsRequest, DescribeFirmwareTaskStatisticsRequestBuilder => ("iotcloud", "DescribeFirmwareTaskStatistics", API_VERSION) {
+        product_id: String => "ProductId",
+        firmware_version: String => "FirmwareVersion",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeFirmwareTaskStatisticsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "SuccessTotal")]
+    pub success_total: u64,
+    #[serde(rename = "FailureTotal")]
+    pub failure_total: u64,
+    #[serde(rename = "UpgradingTotal")]
+    pub upgrading_total: u64,
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+use super::{FirmwareTaskStatus, SearchKeyword, API_VERSION};
+
+request! {
+    DescribeFirmwareTasksRequest, DescribeFirmwareTasksRequestBuilder => ("iotcloud", "DescribeFirmwareTasks", API_VERSION) {
+        product_id: String => "ProductId",
+        firmware_version: String => "FirmwareVersion",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+        filters: Vec<SearchKeyword> => "Filters",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeFirmwareTasksResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskInfos", default)]
+    pub task_infos: Vec<FirmwareTaskInfo>,
+    #[serde(rename = "Total")]
+    pub total: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FirmwareTaskInfo {
+    #[serde(rename = "TaskId")]
+    pub task_id: u64,
+    #[serde(rename = "Status")]
+    pub status: FirmwareTaskStatus,
+    #[serde(rename = "Type", default)]
+    pub task_type: i64,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: i64,
+}
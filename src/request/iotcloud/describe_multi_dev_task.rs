@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DescribeMultiDevTaskRequest, DescribeMultiDevTaskRequestBuilder => ("iotcloud", "DescribeMultiDevTask", API_VERSION) {
+        task_id: String => "TaskId",
+        product_id: String => "ProductId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeMultiDevTaskResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: String,
+    /// 0 created, 1 running, 2 finished, 3 failed.
+    #[serde(rename = "TaskStatus")]
+    pub task_status: u64,
+}
@@ -0,0 +1,136 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use super::{batch_update_firmware::is_valid_device_name, API_VERSION};
+
+request! {
+    DescribeMultiDevicesRequest, DescribeMultiDevicesRequestBuilder => ("iotcloud", "DescribeMultiDevices", API_VERSION) {
+        product_id: String => "ProductId",
+        task_id: String => "TaskId",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeMultiDevicesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: String,
+    #[serde(rename = "DevicesInfo", default)]
+    pub devices_info: Vec<MultiDevicesInfo>,
+    #[serde(rename = "TotalDevNum")]
+    pub total_dev_num: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MultiDevicesInfo {
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    /// 1 when the device was created.
+    #[serde(rename = "Result")]
+    pub result: u64,
+    #[serde(rename = "DevicePsk", default)]
+    pub device_psk: String,
+    #[serde(rename = "DeviceCert", default)]
+    pub device_cert: String,
+    #[serde(rename = "DevicePrivateKey", default)]
+    pub device_private_key: String,
+    #[serde(rename = "ErrMsg", default)]
+    pub err_msg: String,
+}
+
+/// Credentials of a created device, ready to be handed to the device SDK.
+#[derive(Clone, PartialEq)]
+pub struct DeviceCredentials {
+    pub device_name: String,
+    /// PEM encoded certificate, for certificate authenticated products.
+    pub certificate: Option<String>,
+    /// PEM encoded private key of `certificate`.
+    pub private_key: Option<String>,
+    /// Decoded pre-shared key, for key authenticated products.
+    pub psk: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for DeviceCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeviceCredentials")
+            .field("device_name", &self.device_name)
+            .field("certificate", &self.certificate)
+            .field("private_key", &self.private_key.as_ref().map(|_| "***"))
+            .field("psk", &self.psk.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+impl MultiDevicesInfo {
+    pub fn credentials(&self) -> Result<DeviceCredentials, base64::DecodeError> {
+        let non_empty = |s: &str| Some(s.to_string()).filter(|s| !s.is_empty());
+        let psk = match self.device_psk.as_str() {
+            "" => None,
+            psk => Some(STANDARD.decode(psk)?),
+        };
+        Ok(DeviceCredentials {
+            device_name: self.device_name.clone(),
+            certificate: non_empty(&self.device_cert),
+            private_key: non_empty(&self.device_private_key),
+            psk,
+        })
+    }
+}
+
+impl DescribeMultiDevicesResponse {
+    /// Writes the credentials of every created device into `dir/<device name>/`
+    /// as `device.crt`, `device.key` and `device.psk` (raw bytes), and returns
+    /// the directories written. Devices that failed to be created are skipped.
+    pub fn write_credentials<P: AsRef<Path>>(&self, dir: P) -> io::Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        for device in self.devices_info.iter().filter(|device| device.result == 1) {
+            let credentials = device
+                .credentials()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            written.push(credentials.write_to(dir.as_ref())?);
+        }
+        Ok(written)
+    }
+}
+
+impl DeviceCredentials {
+    /// Writes the credentials into `dir/<device name>/`, see
+    /// `DescribeMultiDevicesResponse::write_credentials`.
+    pub fn write_to(&self, dir: &Path) -> io::Result<PathBuf> {
+        // the name becomes a path component
+        if !is_valid_device_name(&self.device_name) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid device name {:?}", self.device_name),
+            ));
+        }
+        let dir = dir.join(&self.device_name);
+        std::fs::create_dir_all(&dir)?;
+        if let Some(ref certificate) = self.certificate {
+            std::fs::write(dir.join("device.crt"), certificate)?;
+        }
+        if let Some(ref private_key) = self.private_key {
+            write_secret(&dir.join("device.key"), private_key.as_bytes())?;
+        }
+        if let Some(ref psk) = self.psk {
+            write_secret(&dir.join("device.psk"), psk)?;
+        }
+        Ok(dir)
+    }
+}
+
+/// Writes a file only the current user can read.
+fn write_secret(path: &Path, content: &[u8]) -> io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    io::Write::write_all(&mut options.open(path)?, content)
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::{ProductMetadata, ProductProperties, API_VERSION};
+
+request! {
+    DescribeProductRequest, DescribeProductRequestBuilder => ("iotcloud", "DescribeProduct", API_VERSION) {
+        product_id: String => "ProductId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    #[serde(rename = "ProductName")]
+    pub product_name: String,
+    #[serde(rename = "ProductMetadata")]
+    pub product_metadata: ProductMetadata,
+    #[serde(rename = "ProductProperties")]
+    pub product_properties: ProductProperties,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::{ProductTaskInfo, API_VERSION};
+
+request! {
+    DescribeProductTaskRequest, DescribeProductTaskRequestBuilder => ("iotcloud", "DescribeProductTask", API_VERSION) {
+        product_id: String => "ProductId",
+        task_id: u64 => "TaskId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeProductTaskResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskInfo")]
+    pub task_info: ProductTaskInfo,
+}
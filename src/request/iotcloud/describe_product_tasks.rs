@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::{ProductTaskInfo, API_VERSION};
+
+request! {
+    DescribeProductTasksRequest, DescribeProductTasksRequestBuilder => ("iotcloud", "DescribeProductTasks", API_VERSION) {
+        product_id: String => "ProductId",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeProductTasksResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "TaskInfos", default)]
+    pub task_infos: Vec<ProductTaskInfo>,
+}
@@ -5,7 +5,7 @@ use serde::ser::SerializeStruct;
 
 use crate::{client::Configuration, Flat, IntoRequest};
 
-use super::{RequestBuilder, ServiceRequest, API_VERSION};
+use super::{ProductProperties, RequestBuilder, ServiceRequest, API_VERSION};
 
 #[derive(Default, Debug)]
 pub struct DescribeProductsRequest {
@@ -35,42 +35,12 @@ pub struct Product {
     pub product_properties: ProductProperties,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct ProductMetadata {
     #[serde(rename = "CreationDate")]
     pub creation_date: i64,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct ProductProperties {
-    #[serde(rename = "ProductDescription")]
-    pub product_description: String,
-    #[serde(rename = "EncryptionType")]
-    pub encryption_type: String,
-    #[serde(rename = "Region")]
-    pub region: String,
-    #[serde(rename = "ProductType")]
-    pub product_type: i32,
-    #[serde(rename = "Format")]
-    pub format: String,
-    #[serde(rename = "Platform")]
-    pub platform: String,
-    #[serde(rename = "Appeui")]
-    pub appeui: String,
-    #[serde(rename = "ModelId")]
-    pub model_id: String,
-    #[serde(rename = "ModelName")]
-    pub model_name: String,
-    #[serde(rename = "ProductKey")]
-    pub product_key: String,
-    #[serde(rename = "RegisterType")]
-    pub register_type: i32,
-    #[serde(rename = "ProductSecret")]
-    pub product_secret: String,
-    #[serde(rename = "RegisterLimit")]
-    pub register_limit: i32,
-}
-
 impl DescribeProductsRequest {
     pub fn builder() -> DescribeProductsRequestBuilder {
         DescribeProductsRequestBuilder::default()
@@ -81,14 +51,7 @@ impl IntoRequest for DescribeProductsRequestBuilder {
     type Request = DescribeProductsRequest;
 
     fn into_request(self, config: Configuration) -> RequestBuilder<Self::Request> {
-        RequestBuilder {
-            credential: config.credential.into(),
-            profile: config.profile.into(),
-            region: config.region,
-            version: Some(API_VERSION.to_string()),
-            inner: self.req,
-            ..Default::default()
-        }
+        RequestBuilder::from_config(config, API_VERSION, self.req)
     }
 }
 
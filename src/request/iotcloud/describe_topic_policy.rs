@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::{TopicPrivilege, API_VERSION};
+
+request! {
+    DescribeTopicPolicyRequest, DescribeTopicPolicyRequestBuilder => ("iotcloud", "DescribeTopicPolicy", API_VERSION) {
+        product_id: String => "ProductID",
+        topic_name: String => "TopicName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeTopicPolicyResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    #[serde(rename = "TopicName")]
+    pub topic_name: String,
+    #[serde(rename = "Privilege")]
+    pub privilege: TopicPrivilege,
+}
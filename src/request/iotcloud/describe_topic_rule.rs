@@ -0,0 +1,37 @@
+use serde::Deserialize;
+
+use super::{TopicRuleAction, API_VERSION};
+
+request! {
+    DescribeTopicRuleRequest, DescribeTopicRuleRequestBuilder => ("iotcloud", "DescribeTopicRule", API_VERSION) {
+        rule_name: String => "RuleName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeTopicRuleResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Rule")]
+    pub rule: TopicRule,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TopicRule {
+    #[serde(rename = "RuleName")]
+    pub rule_name: String,
+    #[serde(rename = "Sql")]
+    pub sql: String,
+    #[serde(
+        rename = "Actions",
+        default,
+        deserialize_with = "super::models::json_string::deserialize"
+    )]
+    pub actions: Vec<TopicRuleAction>,
+    #[serde(rename = "Description", default)]
+    pub description: String,
+    #[serde(rename = "RuleDisabled", default)]
+    pub rule_disabled: bool,
+    #[serde(rename = "CreatedAt", default)]
+    pub created_at: i64,
+}
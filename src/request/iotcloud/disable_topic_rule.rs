@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DisableTopicRuleRequest, DisableTopicRuleRequestBuilder => ("iotcloud", "DisableTopicRule", API_VERSION) {
+        rule_name: String => "RuleName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DisableTopicRuleResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
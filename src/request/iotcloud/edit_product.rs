@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Renames a product or changes its description.
+    EditProductRequest, EditProductRequestBuilder => ("iotcloud", "EditProduct", API_VERSION) {
+        product_id: String => "ProductId",
+        product_name: String => "ProductName",
+        product_description: String => "ProductDescription",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EditProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
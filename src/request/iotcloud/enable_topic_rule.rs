@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    EnableTopicRuleRequest, EnableTopicRuleRequestBuilder => ("iotcloud", "EnableTopicRule", API_VERSION) {
+        rule_name: String => "RuleName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EnableTopicRuleResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
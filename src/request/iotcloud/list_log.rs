@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Device log of the cloud side: connections, messages, rule engine runs.
+    /// Pass the `Context` of a response to get the next page.
+    ListLogRequest, ListLogRequestBuilder => ("iotcloud", "ListLog", API_VERSION) {
+        /// Start of the range, in milliseconds.
+        min_time: u64 => "MinTime",
+        max_time: u64 => "MaxTime",
+        /// e.g. `productid:ABCDEFGHIJ devicename:lamp-1`.
+        keywords: String => "Keywords",
+        context: String => "Context",
+        max_num: u64 => "MaxNum",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ListLogResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Context", default)]
+    pub context: String,
+    /// Whether there is nothing left after this page.
+    #[serde(rename = "Listover")]
+    pub list_over: bool,
+    #[serde(rename = "Results", default)]
+    pub results: Vec<ClsLogItem>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClsLogItem {
+    #[serde(rename = "Content", default)]
+    pub content: String,
+    #[serde(rename = "Devicename", default)]
+    pub device_name: String,
+    #[serde(rename = "Productid", default)]
+    pub product_id: String,
+    #[serde(rename = "Requestid", default)]
+    pub request_id: String,
+    #[serde(rename = "Result", default)]
+    pub result: String,
+    #[serde(rename = "Scene", default)]
+    pub scene: String,
+    #[serde(rename = "Time", default)]
+    pub time: String,
+    #[serde(rename = "Userid", default)]
+    pub user_id: String,
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Log uploaded by the device SDK. Pass the `Context` of a response to
+    /// get the next page.
+    ListSDKLogRequest, ListSDKLogRequestBuilder => ("iotcloud", "ListSDKLog", API_VERSION) {
+        /// Start of the range, in milliseconds.
+        min_time: u64 => "MinTime",
+        max_time: u64 => "MaxTime",
+        keywords: String => "Keywords",
+        context: String => "Context",
+        max_num: u64 => "MaxNum",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ListSDKLogResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Context", default)]
+    pub context: String,
+    #[serde(rename = "Listover")]
+    pub list_over: bool,
+    #[serde(rename = "Results", default)]
+    pub results: Vec<SdkLogItem>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SdkLogItem {
+    #[serde(rename = "ProductID", default)]
+    pub product_id: String,
+    #[serde(rename = "DeviceName", default)]
+    pub device_name: String,
+    #[serde(rename = "Level", default)]
+    pub level: String,
+    #[serde(rename = "DateTime", default)]
+    pub date_time: String,
+    #[serde(rename = "Content", default)]
+    pub content: String,
+}
@@ -1,7 +1,107 @@
 pub mod batch_update_firmware;
+pub mod bind_devices;
+pub mod cancel_device_firmware_task;
+pub mod create_device;
+pub mod create_multi_device;
+pub mod create_multi_devices_task;
+pub mod create_product;
+pub mod create_topic_policy;
+pub mod create_topic_rule;
+pub mod delete_device;
+pub mod delete_firmware;
+pub mod delete_product;
+pub mod delete_topic_policy;
+pub mod delete_topic_rule;
+pub mod describe_device;
+pub mod describe_device_client_key;
+pub mod describe_device_resource;
+pub mod describe_device_resources;
+pub mod describe_device_shadow;
+pub mod describe_devices;
+pub mod describe_firmware;
+pub mod describe_firmware_task;
+pub mod describe_firmware_task_devices;
+pub mod describe_firmware_task_statistics;
+pub mod describe_firmware_tasks;
+pub mod describe_multi_dev_task;
+pub mod describe_multi_devices;
+pub mod describe_product;
+pub mod describe_product_task;
+pub mod describe_product_tasks;
 pub mod describe_products;
+pub mod describe_topic_policy;
+pub mod describe_topic_rule;
+pub mod disable_topic_rule;
+pub mod edit_product;
+pub mod enable_topic_rule;
+pub mod list_log;
+pub mod list_sdk_log;
+pub mod models;
+pub mod publish_as_device;
+pub mod publish_broadcast_message;
+pub mod publish_message;
+pub mod publish_rrpc_message;
+pub mod publish_to_device;
+pub mod replace_topic_rule;
+pub mod retry_device_firmware_task;
+pub mod unbind_devices;
+pub mod update_device_available_state;
+pub mod update_device_psk;
+pub mod update_device_shadow;
+pub mod update_topic_policy;
+pub mod upload_firmware;
 
 pub use batch_update_firmware::*;
+pub use bind_devices::*;
+pub use cancel_device_firmware_task::*;
+pub use create_device::*;
+pub use create_multi_device::*;
+pub use create_multi_devices_task::*;
+pub use create_product::*;
+pub use create_topic_policy::*;
+pub use create_topic_rule::*;
+pub use delete_device::*;
+pub use delete_firmware::*;
+pub use delete_product::*;
+pub use delete_topic_policy::*;
+pub use delete_topic_rule::*;
+pub use describe_device::*;
+pub use describe_device_client_key::*;
+pub use describe_device_resource::*;
+pub use describe_device_resources::*;
+pub use describe_device_shadow::*;
+pub use describe_devices::*;
+pub use describe_firmware::*;
+pub use describe_firmware_task::*;
+pub use describe_firmware_task_devices::*;
+pub use describe_firmware_task_statistics::*;
+pub use describe_firmware_tasks::*;
+pub use describe_multi_dev_task::*;
+pub use describe_multi_devices::*;
+pub use describe_product::*;
+pub use describe_product_task::*;
+pub use describe_product_tasks::*;
 pub use describe_products::*;
+pub use describe_topic_policy::*;
+pub use describe_topic_rule::*;
+pub use disable_topic_rule::*;
+pub use edit_product::*;
+pub use enable_topic_rule::*;
+pub use list_log::*;
+pub use list_sdk_log::*;
+pub use models::*;
+pub use publish_as_device::*;
+pub use publish_broadcast_message::*;
+pub use publish_message::*;
+pub use publish_rrpc_message::*;
+pub use publish_to_device::*;
+pub use replace_topic_rule::*;
+pub use retry_device_firmware_task::*;
+pub use unbind_devices::*;
+pub use update_device_available_state::*;
+pub use update_device_psk::*;
+pub use update_device_shadow::*;
+pub use update_topic_policy::*;
+pub use upload_firmware::*;
 
 use super::{RequestBuilder, ServiceRequest, API_VERSION};
@@ -0,0 +1,480 @@
+use std::{collections::HashMap, convert::Infallible, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::FlatField;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeviceTag {
+    #[serde(rename = "Tag")]
+    pub tag: String,
+    /// 1 for integer, 2 for string.
+    #[serde(rename = "Type")]
+    pub tag_type: u64,
+    #[serde(rename = "Value")]
+    pub value: String,
+    #[serde(rename = "Name", default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+flat_field!(DeviceTag {
+    tag => "Tag",
+    tag_type => "Type",
+    value => "Value",
+    name => "Name",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Attribute {
+    #[serde(rename = "Tags")]
+    pub tags: Vec<DeviceTag>,
+}
+
+flat_field!(Attribute { tags => "Tags" });
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeviceInfo {
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    /// 0 offline, 1 online.
+    #[serde(rename = "Online", default)]
+    pub online: u64,
+    #[serde(rename = "LoginTime", default)]
+    pub login_time: u64,
+    #[serde(rename = "Version", default)]
+    pub version: String,
+    #[serde(rename = "DeviceCert", default)]
+    pub device_cert: String,
+    #[serde(rename = "DevicePsk", default)]
+    pub device_psk: String,
+    #[serde(rename = "Tags", default)]
+    pub tags: Vec<DeviceTag>,
+    #[serde(rename = "DeviceType", default)]
+    pub device_type: u64,
+    #[serde(rename = "Imei", default)]
+    pub imei: String,
+    #[serde(rename = "Isp", default)]
+    pub isp: u64,
+    #[serde(rename = "ConnIP", default)]
+    pub conn_ip: u64,
+    #[serde(rename = "LastUpdateTime", default)]
+    pub last_update_time: u64,
+    #[serde(rename = "FirstOnlineTime", default)]
+    pub first_online_time: u64,
+    #[serde(rename = "LastOfflineTime", default)]
+    pub last_offline_time: u64,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: u64,
+    #[serde(rename = "LogLevel", default)]
+    pub log_level: u64,
+    /// 0 not activated, 1 activated.
+    #[serde(rename = "CertState", default)]
+    pub cert_state: u64,
+    /// 0 disabled, 1 enabled.
+    #[serde(rename = "EnableState", default)]
+    pub enable_state: u64,
+    #[serde(rename = "ClientIP", default)]
+    pub client_ip: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeviceResourceInfo {
+    #[serde(rename = "ProductID")]
+    pub product_id: String,
+    #[serde(rename = "ProductName", default)]
+    pub product_name: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Md5", default)]
+    pub md5: String,
+    #[serde(rename = "Size", default)]
+    pub size: u64,
+    #[serde(rename = "UpdateTime", default)]
+    pub update_time: String,
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    /// 0 uploading, 1 uploaded, 2 failed.
+    #[serde(rename = "Status", default)]
+    pub status: u64,
+    #[serde(rename = "Percent", default)]
+    pub percent: u64,
+}
+
+/// How devices of a product authenticate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncryptionType {
+    Certificate,
+    Key,
+    Tid,
+    Unknown(String),
+}
+
+impl AsRef<str> for EncryptionType {
+    fn as_ref(&self) -> &str {
+        match self {
+            EncryptionType::Certificate => "1",
+            EncryptionType::Key => "2",
+            EncryptionType::Tid => "3",
+            EncryptionType::Unknown(s) => s,
+        }
+    }
+}
+
+impl FromStr for EncryptionType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => EncryptionType::Certificate,
+            "2" => EncryptionType::Key,
+            "3" => EncryptionType::Tid,
+            s => EncryptionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+serde_str!(EncryptionType);
+
+/// Data format of the messages of a product.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProductFormat {
+    Json,
+    Custom,
+    Unknown(String),
+}
+
+impl AsRef<str> for ProductFormat {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProductFormat::Json => "json",
+            ProductFormat::Custom => "custom",
+            ProductFormat::Unknown(s) => s,
+        }
+    }
+}
+
+impl FromStr for ProductFormat {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => ProductFormat::Json,
+            "custom" => ProductFormat::Custom,
+            s => ProductFormat::Unknown(s.to_string()),
+        })
+    }
+}
+
+serde_str!(ProductFormat);
+
+/// Dynamic registration of the devices of a product.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(from = "i64", into = "i64")]
+pub enum RegisterType {
+    Disabled,
+    /// Devices are created when they first register.
+    AutoCreate,
+    /// Only devices created beforehand may register.
+    PreCreate,
+    Unknown(i64),
+}
+
+impl From<i64> for RegisterType {
+    fn from(register_type: i64) -> Self {
+        match register_type {
+            0 => RegisterType::Disabled,
+            1 => RegisterType::AutoCreate,
+            2 => RegisterType::PreCreate,
+            register_type => RegisterType::Unknown(register_type),
+        }
+    }
+}
+
+impl From<RegisterType> for i64 {
+    fn from(register_type: RegisterType) -> Self {
+        match register_type {
+            RegisterType::Disabled => 0,
+            RegisterType::AutoCreate => 1,
+            RegisterType::PreCreate => 2,
+            RegisterType::Unknown(register_type) => register_type,
+        }
+    }
+}
+
+flat_field!(RegisterType as i64);
+
+/// Properties of a product, as returned by `DescribeProducts`,
+/// `DescribeProduct` and `CreateProduct`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProductProperties {
+    #[serde(rename = "ProductDescription")]
+    pub product_description: String,
+    #[serde(rename = "EncryptionType")]
+    pub encryption_type: String,
+    #[serde(rename = "Region")]
+    pub region: String,
+    #[serde(rename = "ProductType")]
+    pub product_type: i32,
+    #[serde(rename = "Format")]
+    pub format: String,
+    #[serde(rename = "Platform")]
+    pub platform: String,
+    #[serde(rename = "Appeui")]
+    pub appeui: String,
+    #[serde(rename = "ModelId")]
+    pub model_id: String,
+    #[serde(rename = "ModelName")]
+    pub model_name: String,
+    #[serde(rename = "ProductKey")]
+    pub product_key: String,
+    #[serde(rename = "RegisterType")]
+    pub register_type: i32,
+    #[serde(rename = "ProductSecret")]
+    pub product_secret: String,
+    #[serde(rename = "RegisterLimit")]
+    pub register_limit: i32,
+}
+
+/// Properties of a product sent to `CreateProduct`. Unset fields are left
+/// out of the request.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ProductPropertiesInput {
+    #[serde(rename = "ProductDescription", skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<String>,
+    #[serde(rename = "EncryptionType", skip_serializing_if = "Option::is_none")]
+    pub encryption_type: Option<EncryptionType>,
+    #[serde(rename = "Region", skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// 0 normal, 2 NB-IoT, 3 LoRa, 4 gateway.
+    #[serde(rename = "ProductType", skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<i64>,
+    #[serde(rename = "Format", skip_serializing_if = "Option::is_none")]
+    pub format: Option<ProductFormat>,
+    #[serde(rename = "Platform", skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    #[serde(rename = "Appeui", skip_serializing_if = "Option::is_none")]
+    pub appeui: Option<String>,
+    #[serde(rename = "ModelId", skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+    #[serde(rename = "ModelName", skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>,
+    #[serde(rename = "ProductKey", skip_serializing_if = "Option::is_none")]
+    pub product_key: Option<String>,
+    #[serde(rename = "RegisterType", skip_serializing_if = "Option::is_none")]
+    pub register_type: Option<RegisterType>,
+    #[serde(rename = "ProductSecret", skip_serializing_if = "Option::is_none")]
+    pub product_secret: Option<String>,
+    #[serde(rename = "RegisterLimit", skip_serializing_if = "Option::is_none")]
+    pub register_limit: Option<i64>,
+}
+
+flat_field!(ProductPropertiesInput {
+    product_description => "ProductDescription",
+    encryption_type => "EncryptionType",
+    region => "Region",
+    product_type => "ProductType",
+    format => "Format",
+    platform => "Platform",
+    appeui => "Appeui",
+    model_id => "ModelId",
+    model_name => "ModelName",
+    product_key => "ProductKey",
+    register_type => "RegisterType",
+    product_secret => "ProductSecret",
+    register_limit => "RegisterLimit",
+});
+
+/// A `Key`/`Value` filter of the Describe actions.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SearchKeyword {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Value", default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl SearchKeyword {
+    pub fn new<K: Into<String>, V: Into<String>>(key: K, value: V) -> SearchKeyword {
+        SearchKeyword {
+            key: key.into(),
+            value: Some(value.into()),
+        }
+    }
+}
+
+flat_field!(SearchKeyword {
+    key => "Key",
+    value => "Value",
+});
+
+/// What a topic rule does with the messages its `Sql` selects.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum TopicRuleAction {
+    #[serde(rename = "ckafka")]
+    Ckafka(CkafkaAction),
+    #[serde(rename = "cmqqueue")]
+    Cmq(CmqAction),
+    #[serde(rename = "republish")]
+    Republish(RepublishAction),
+    #[serde(rename = "forward")]
+    Http(HttpAction),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CkafkaAction {
+    pub region: String,
+    /// Id of the CKafka instance, e.g. `ckafka-abcd1234`.
+    pub instance: String,
+    pub topic: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CmqAction {
+    pub region: String,
+    pub queue_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RepublishAction {
+    /// Topic the message is published to, e.g. `ABCDEFGHIJ/lamp-1/control`.
+    pub topic: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpAction {
+    /// Url the message is POSTed to.
+    #[serde(rename = "api")]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+/// Definition of a topic rule. The api takes `Actions` as a JSON encoded
+/// string, which is handled here.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TopicRulePayload {
+    /// e.g. `SELECT * FROM 'ABCDEFGHIJ/+/event'`.
+    #[serde(rename = "Sql")]
+    pub sql: String,
+    #[serde(rename = "Actions", default, with = "json_string")]
+    pub actions: Vec<TopicRuleAction>,
+    #[serde(
+        rename = "Description",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(
+        rename = "RuleDisabled",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rule_disabled: Option<bool>,
+}
+
+impl FlatField for TopicRulePayload {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        self.sql.flat_into(hm, &format!("{}.Sql", key));
+        hm.insert(
+            format!("{}.Actions", key),
+            serde_json::to_string(&self.actions).unwrap_or_default(),
+        );
+        self.description
+            .flat_into(hm, &format!("{}.Description", key));
+        self.rule_disabled
+            .flat_into(hm, &format!("{}.RuleDisabled", key));
+    }
+}
+
+/// (De)serializes a value as a string holding its JSON encoding.
+pub(super) mod json_string {
+    use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        let json = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        serde_json::from_str(&json).map_err(serde::de::Error::custom)
+    }
+}
+
+/// What devices may do on a topic.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(from = "i64", into = "i64")]
+pub enum TopicPrivilege {
+    Publish,
+    Subscribe,
+    PublishSubscribe,
+    Unknown(i64),
+}
+
+impl From<i64> for TopicPrivilege {
+    fn from(privilege: i64) -> Self {
+        match privilege {
+            1 => TopicPrivilege::Publish,
+            2 => TopicPrivilege::Subscribe,
+            3 => TopicPrivilege::PublishSubscribe,
+            privilege => TopicPrivilege::Unknown(privilege),
+        }
+    }
+}
+
+impl From<TopicPrivilege> for i64 {
+    fn from(privilege: TopicPrivilege) -> Self {
+        match privilege {
+            TopicPrivilege::Publish => 1,
+            TopicPrivilege::Subscribe => 2,
+            TopicPrivilege::PublishSubscribe => 3,
+            TopicPrivilege::Unknown(privilege) => privilege,
+        }
+    }
+}
+
+flat_field!(TopicPrivilege as i64);
+
+/// A batch task of a product, e.g. creating devices from a file.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProductTaskInfo {
+    #[serde(rename = "Id")]
+    pub id: u64,
+    #[serde(rename = "UserId", default)]
+    pub user_id: u64,
+    /// 0 create devices, 1 create devices from a file.
+    #[serde(rename = "Type", default)]
+    pub task_type: u64,
+    /// 0 waiting, 1 running, 2 finished.
+    #[serde(rename = "State", default)]
+    pub state: u64,
+    #[serde(rename = "ParametersType", default)]
+    pub parameters_type: String,
+    #[serde(rename = "Parameters", default)]
+    pub parameters: String,
+    #[serde(rename = "ResultType", default)]
+    pub result_type: String,
+    #[serde(rename = "Result", default)]
+    pub result: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: u64,
+    #[serde(rename = "UpdateTime", default)]
+    pub update_time: u64,
+    #[serde(rename = "CompleteTime", default)]
+    pub complete_time: u64,
+    #[serde(rename = "DoneCount", default)]
+    pub done_count: u64,
+    #[serde(rename = "FailCount", default)]
+    pub fail_count: u64,
+}
@@ -0,0 +1,26 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Publishes an uplink message on behalf of a LoRa device.
+    PublishAsDeviceRequest, PublishAsDeviceRequestBuilder => ("iotcloud", "PublishAsDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        port: u64 => "Port",
+        payload: String => "Payload",
+    }
+}
+
+impl PublishAsDeviceRequestBuilder {
+    pub fn set_payload_bytes<B: AsRef<[u8]>>(self, payload: B) -> Self {
+        self.set_payload(STANDARD.encode(payload))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PublishAsDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
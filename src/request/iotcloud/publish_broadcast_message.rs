@@ -0,0 +1,34 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use super::API_VERSION;
+
+request! {
+    /// Publishes a message to every online device of a product.
+    PublishBroadcastMessageRequest, PublishBroadcastMessageRequestBuilder => ("iotcloud", "PublishBroadcastMessage", API_VERSION) {
+        product_id: String => "ProductId",
+        payload: String => "Payload",
+        qos: u64 => "Qos",
+        payload_encoding: String => "PayloadEncoding",
+    }
+}
+
+impl PublishBroadcastMessageRequestBuilder {
+    /// Sends `payload` base64 encoded, the devices receive the raw bytes.
+    pub fn set_payload_bytes<B: AsRef<[u8]>>(self, payload: B) -> Self {
+        self.set_payload(STANDARD.encode(payload))
+            .set_payload_encoding("base64".to_string())
+    }
+
+    pub fn set_payload_json<T: Serialize>(self, payload: &T) -> serde_json::Result<Self> {
+        Ok(self.set_payload(serde_json::to_string(payload)?))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PublishBroadcastMessageResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: u64,
+}
@@ -0,0 +1,34 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use super::API_VERSION;
+
+request! {
+    /// Publishes a message on a topic of a device.
+    PublishMessageRequest, PublishMessageRequestBuilder => ("iotcloud", "PublishMessage", API_VERSION) {
+        topic: String => "Topic",
+        payload: String => "Payload",
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        qos: u64 => "Qos",
+        payload_encoding: String => "PayloadEncoding",
+    }
+}
+
+impl PublishMessageRequestBuilder {
+    /// Sends `payload` base64 encoded, the device receives the raw bytes.
+    pub fn set_payload_bytes<B: AsRef<[u8]>>(self, payload: B) -> Self {
+        self.set_payload(STANDARD.encode(payload))
+            .set_payload_encoding("base64".to_string())
+    }
+
+    pub fn set_payload_json<T: Serialize>(self, payload: &T) -> serde_json::Result<Self> {
+        Ok(self.set_payload(serde_json::to_string(payload)?))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PublishMessageResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
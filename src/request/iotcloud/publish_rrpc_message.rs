@@ -0,0 +1,41 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use super::API_VERSION;
+
+request! {
+    /// Sends a request to a device and waits for its reply (revert RPC).
+    /// `Payload` is base64 encoded.
+    PublishRRPCMessageRequest, PublishRRPCMessageRequestBuilder => ("iotcloud", "PublishRRPCMessage", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        payload: String => "Payload",
+    }
+}
+
+impl PublishRRPCMessageRequestBuilder {
+    pub fn set_payload_bytes<B: AsRef<[u8]>>(self, payload: B) -> Self {
+        self.set_payload(STANDARD.encode(payload))
+    }
+
+    pub fn set_payload_json<T: Serialize>(self, payload: &T) -> serde_json::Result<Self> {
+        Ok(self.set_payload_bytes(serde_json::to_vec(payload)?))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PublishRRPCMessageResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "MessageId")]
+    pub message_id: u64,
+    #[serde(rename = "PayloadBase64")]
+    pub payload_base64: String,
+}
+
+impl PublishRRPCMessageResponse {
+    /// The reply of the device.
+    pub fn payload(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        STANDARD.decode(&self.payload_base64)
+    }
+}
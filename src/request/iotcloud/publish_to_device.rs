@@ -0,0 +1,26 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Sends a downlink message to a LoRa device.
+    PublishToDeviceRequest, PublishToDeviceRequestBuilder => ("iotcloud", "PublishToDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        port: u64 => "Port",
+        payload: String => "Payload",
+    }
+}
+
+impl PublishToDeviceRequestBuilder {
+    pub fn set_payload_bytes<B: AsRef<[u8]>>(self, payload: B) -> Self {
+        self.set_payload(STANDARD.encode(payload))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PublishToDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
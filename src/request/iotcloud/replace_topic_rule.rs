@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::{TopicRulePayload, API_VERSION};
+
+request! {
+    /// Replaces the whole definition of an existing rule.
+    ReplaceTopicRuleRequest, ReplaceTopicRuleRequestBuilder => ("iotcloud", "ReplaceTopicRule", API_VERSION) {
+        rule_name: String => "RuleName",
+        topic_rule_payload: TopicRulePayload => "TopicRulePayload",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReplaceTopicRuleResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
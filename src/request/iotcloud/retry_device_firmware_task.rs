@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Restarts the failed update of one device within a firmware task.
+    RetryDeviceFirmwareTaskRequest, RetryDeviceFirmwareTaskRequestBuilder => ("iotcloud", "RetryDeviceFirmwareTask", API_VERSION) {
+        product_id: String => "ProductID",
+        device_name: String => "DeviceName",
+        firmware_version: String => "FirmwareVersion",
+        task_id: u64 => "TaskId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RetryDeviceFirmwareTaskResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
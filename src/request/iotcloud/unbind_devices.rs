@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    UnbindDevicesRequest, UnbindDevicesRequestBuilder => ("iotcloud", "UnbindDevices", API_VERSION) {
+        gateway_product_id: String => "GatewayProductId",
+        gateway_device_name: String => "GatewayDeviceName",
+        product_id: String => "ProductId",
+        device_names: Vec<String> => "DeviceNames",
+        skey: String => "Skey",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UnbindDevicesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
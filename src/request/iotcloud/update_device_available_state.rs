@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Enables (`EnableState` 1) or disables (0) a device.
+    UpdateDeviceAvailableStateRequest, UpdateDeviceAvailableStateRequestBuilder => ("iotcloud", "UpdateDeviceAvailableState", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        enable_state: u64 => "EnableState",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateDeviceAvailableStateResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
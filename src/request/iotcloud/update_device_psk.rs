@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Replaces the pre-shared key of a device.
+    UpdateDevicePSKRequest, UpdateDevicePSKRequestBuilder => ("iotcloud", "UpdateDevicePSK", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// Base64 encoded key.
+        psk: String => "Psk",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateDevicePSKResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
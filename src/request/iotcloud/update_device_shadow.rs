@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    UpdateDeviceShadowRequest, UpdateDeviceShadowRequestBuilder => ("iotcloud", "UpdateDeviceShadow", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        state: String => "State",
+        shadow_version: u64 => "ShadowVersion",
+        prefix: String => "Prefix",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateDeviceShadowResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// The shadow document as a JSON string.
+    #[serde(rename = "Data")]
+    pub data: String,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::{TopicPrivilege, API_VERSION};
+
+request! {
+    /// Renames a topic or changes what devices may do on it.
+    UpdateTopicPolicyRequest, UpdateTopicPolicyRequestBuilder => ("iotcloud", "UpdateTopicPolicy", API_VERSION) {
+        product_id: String => "ProductID",
+        topic_name: String => "TopicName",
+        new_topic_name: String => "NewTopicName",
+        privilege: TopicPrivilege => "Privilege",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateTopicPolicyResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use std::path::Path;
+
+use super::{batch_update_firmware::file_digest, API_VERSION};
+
+request! {
+    /// Registers a firmware file already uploaded to the COS url returned by
+    /// `GetCOSURL`.
+    UploadFirmwareRequest, UploadFirmwareRequestBuilder => ("iotcloud", "UploadFirmware", API_VERSION) {
+        product_id: String => "ProductId",
+        firmware_version: String => "FirmwareVersion",
+        md5sum: String => "Md5sum",
+        file_size: u64 => "FileSize",
+        firmware_name: String => "FirmwareName",
+        firmware_description: String => "FirmwareDescription",
+        fw_type: String => "FwType",
+    }
+}
+
+impl UploadFirmwareRequestBuilder {
+    /// Sets `Md5sum` and `FileSize` from the firmware file at `path`.
+    pub fn set_firmware_file<P: AsRef<Path>>(self, path: P) -> std::io::Result<Self> {
+        let (md5, size) = file_digest(path.as_ref())?;
+        Ok(self.set_md5sum(md5).set_file_size(size))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UploadFirmwareResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use super::API_VERSION;
+
+request! {
+    /// Calls an action of the data template without waiting for the device.
+    CallDeviceActionAsyncRequest, CallDeviceActionAsyncRequestBuilder => ("iotexplorer", "CallDeviceActionAsync", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        action_id: String => "ActionId",
+        /// Input of the action as JSON.
+        input_params: String => "InputParams",
+        timestamp: i64 => "Timestamp",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CallDeviceActionAsyncResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "ClientToken")]
+    pub client_token: String,
+    #[serde(rename = "Status", default)]
+    pub status: String,
+}
+
+impl CallDeviceActionAsyncRequestBuilder {
+    pub fn set_input_json<T: Serialize>(self, input: &T) -> serde_json::Result<Self> {
+        Ok(self.set_input_params(serde_json::to_string(input)?))
+    }
+}
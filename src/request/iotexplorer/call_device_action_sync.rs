@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use super::API_VERSION;
+
+request! {
+    /// Calls an action of the data template and waits for the device to answer.
+    CallDeviceActionSyncRequest, CallDeviceActionSyncRequestBuilder => ("iotexplorer", "CallDeviceActionSync", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        action_id: String => "ActionId",
+        /// Input of the action as JSON.
+        input_params: String => "InputParams",
+        timestamp: i64 => "Timestamp",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CallDeviceActionSyncResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "ClientToken")]
+    pub client_token: String,
+    /// Output of the action as JSON.
+    #[serde(rename = "OutputParams", default)]
+    pub output_params: String,
+    #[serde(rename = "Status", default)]
+    pub status: String,
+}
+
+impl CallDeviceActionSyncRequestBuilder {
+    pub fn set_input_json<T: Serialize>(self, input: &T) -> serde_json::Result<Self> {
+        Ok(self.set_input_params(serde_json::to_string(input)?))
+    }
+}
+
+impl CallDeviceActionSyncResponse {
+    pub fn output<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(&self.output_params)
+    }
+}
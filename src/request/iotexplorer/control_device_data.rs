@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use super::API_VERSION;
+
+request! {
+    /// Sets properties of a device.
+    ControlDeviceDataRequest, ControlDeviceDataRequestBuilder => ("iotexplorer", "ControlDeviceData", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// Properties to set as JSON, e.g. `{"power_switch":1}`.
+        data: String => "Data",
+        /// `report` or `control` (default).
+        method: String => "Method",
+        device_id: String => "DeviceId",
+        data_timestamp: i64 => "DataTimestamp",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ControlDeviceDataResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data", default)]
+    pub data: String,
+    #[serde(rename = "Result", default)]
+    pub result: String,
+}
+
+impl ControlDeviceDataRequestBuilder {
+    pub fn set_data_json<T: Serialize>(self, data: &T) -> serde_json::Result<Self> {
+        Ok(self.set_data(serde_json::to_string(data)?))
+    }
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::{DeviceData, API_VERSION};
+
+request! {
+    CreateDeviceRequest, CreateDeviceRequestBuilder => ("iotexplorer", "CreateDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        defined_psk: String => "DefinedPsk",
+        dev_eui: String => "DevEUI",
+        app_key: String => "AppKey",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data")]
+    pub data: DeviceData,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::{ProjectEntry, API_VERSION};
+
+request! {
+    CreateProjectRequest, CreateProjectRequestBuilder => ("iotexplorer", "CreateProject", API_VERSION) {
+        project_name: String => "ProjectName",
+        project_description: String => "ProjectDescription",
+        instance_id: String => "InstanceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateProjectResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Project")]
+    pub project: ProjectEntry,
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+use super::{ProductEntry, API_VERSION};
+
+request! {
+    CreateStudioProductRequest, CreateStudioProductRequestBuilder => ("iotexplorer", "CreateStudioProduct", API_VERSION) {
+        product_name: String => "ProductName",
+        category_id: u64 => "CategoryId",
+        /// 0 normal device, 5 gateway.
+        product_type: u64 => "ProductType",
+        /// `1` certificate, `2` key.
+        encryption_type: String => "EncryptionType",
+        /// e.g. `wifi`, `cellular`, `lora`.
+        net_type: String => "NetType",
+        /// 1 data template.
+        data_protocol: u64 => "DataProtocol",
+        product_desc: String => "ProductDesc",
+        project_id: String => "ProjectId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateStudioProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Product")]
+    pub product: ProductEntry,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteDeviceRequest, DeleteDeviceRequestBuilder => ("iotexplorer", "DeleteDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteProjectRequest, DeleteProjectRequestBuilder => ("iotexplorer", "DeleteProject", API_VERSION) {
+        project_id: String => "ProjectId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteProjectResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
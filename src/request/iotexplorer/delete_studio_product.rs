@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteStudioProductRequest, DeleteStudioProductRequestBuilder => ("iotexplorer", "DeleteStudioProduct", API_VERSION) {
+        product_id: String => "ProductId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteStudioProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
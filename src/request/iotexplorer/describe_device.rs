@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{DeviceInfo, API_VERSION};
+
+request! {
+    DescribeDeviceRequest, DescribeDeviceRequestBuilder => ("iotexplorer", "DescribeDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// `<ProductId>/<DeviceName>`, instead of both.
+        device_id: String => "DeviceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Device")]
+    pub device: DeviceInfo,
+}
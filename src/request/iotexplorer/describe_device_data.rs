@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use super::API_VERSION;
+
+request! {
+    /// Latest reported properties of a device.
+    DescribeDeviceDataRequest, DescribeDeviceDataRequestBuilder => ("iotexplorer", "DescribeDeviceData", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        device_id: String => "DeviceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceDataResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// Latest properties as JSON, each as `{"Value":..,"LastUpdate":..}`.
+    #[serde(rename = "Data")]
+    pub data: String,
+}
+
+impl DescribeDeviceDataResponse {
+    pub fn properties(&self) -> serde_json::Result<HashMap<String, PropertyValue>> {
+        serde_json::from_str(&self.data)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct PropertyValue {
+    #[serde(rename = "Value")]
+    pub value: serde_json::Value,
+    /// Milliseconds since the epoch.
+    #[serde(rename = "LastUpdate", default)]
+    pub last_update: i64,
+}
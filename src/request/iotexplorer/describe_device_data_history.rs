@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Past values of a property. Pass the `Context` of a response to get the next page.
+    DescribeDeviceDataHistoryRequest, DescribeDeviceDataHistoryRequestBuilder => ("iotexplorer", "DescribeDeviceDataHistory", API_VERSION) {
+        /// Start of the range, in milliseconds.
+        min_time: u64 => "MinTime",
+        max_time: u64 => "MaxTime",
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// Id of the property.
+        field_name: String => "FieldName",
+        limit: u64 => "Limit",
+        context: String => "Context",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceDataHistoryResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "FieldName", default)]
+    pub field_name: String,
+    #[serde(rename = "Listover")]
+    pub list_over: bool,
+    #[serde(rename = "Context", default)]
+    pub context: String,
+    #[serde(rename = "Results", default)]
+    pub results: Vec<DeviceDataHistoryItem>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeviceDataHistoryItem {
+    /// Milliseconds since the epoch, as a string.
+    #[serde(rename = "Time")]
+    pub time: String,
+    #[serde(rename = "Value")]
+    pub value: String,
+}
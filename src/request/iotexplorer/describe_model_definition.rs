@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+use super::{DataTemplate, ProductModelDefinition, API_VERSION};
+
+request! {
+    /// Returns the data template of a product, see `DataTemplate`. IoT
+    /// Explorer has no `GetProductDataTemplate` action: this is how a
+    /// template is read.
+    DescribeModelDefinitionRequest, DescribeModelDefinitionRequestBuilder => ("iotexplorer", "DescribeModelDefinition", API_VERSION) {
+        product_id: String => "ProductId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeModelDefinitionResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Model")]
+    pub model: ProductModelDefinition,
+}
+
+impl DescribeModelDefinitionResponse {
+    pub fn data_template(&self) -> serde_json::Result<DataTemplate> {
+        serde_json::from_str(&self.model.model_define)
+    }
+}
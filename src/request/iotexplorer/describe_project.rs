@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::{ProjectEntryEx, API_VERSION};
+
+request! {
+    DescribeProjectRequest, DescribeProjectRequestBuilder => ("iotexplorer", "DescribeProject", API_VERSION) {
+        project_id: String => "ProjectId",
+        instance_id: String => "InstanceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeProjectResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Project")]
+    pub project: ProjectEntryEx,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::{ProductEntry, API_VERSION};
+
+request! {
+    DescribeStudioProductRequest, DescribeStudioProductRequestBuilder => ("iotexplorer", "DescribeStudioProduct", API_VERSION) {
+        product_id: String => "ProductId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeStudioProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Product")]
+    pub product: ProductEntry,
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::{DeviceInfo, API_VERSION};
+
+request! {
+    GetDeviceListRequest, GetDeviceListRequestBuilder => ("iotexplorer", "GetDeviceList", API_VERSION) {
+        product_id: String => "ProductId",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+        firmware_version: String => "FirmwareVersion",
+        device_name: String => "DeviceName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetDeviceListResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Devices", default)]
+    pub devices: Vec<DeviceInfo>,
+    #[serde(rename = "Total")]
+    pub total: u64,
+}
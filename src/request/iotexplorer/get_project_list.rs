@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::{ProjectEntryEx, API_VERSION};
+
+request! {
+    GetProjectListRequest, GetProjectListRequestBuilder => ("iotexplorer", "GetProjectList", API_VERSION) {
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+        instance_id: String => "InstanceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetProjectListResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Projects", default)]
+    pub projects: Vec<ProjectEntryEx>,
+    #[serde(rename = "Total")]
+    pub total: u64,
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::{ProductEntry, API_VERSION};
+
+request! {
+    GetStudioProductListRequest, GetStudioProductListRequestBuilder => ("iotexplorer", "GetStudioProductList", API_VERSION) {
+        project_id: String => "ProjectId",
+        /// `dev` or `released`.
+        dev_status: String => "DevStatus",
+        offset: u64 => "Offset",
+        limit: u64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetStudioProductListResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Products", default)]
+    pub products: Vec<ProductEntry>,
+    #[serde(rename = "Total")]
+    pub total: u64,
+}
@@ -0,0 +1,47 @@
+pub mod call_device_action_async;
+pub mod call_device_action_sync;
+pub mod control_device_data;
+pub mod create_device;
+pub mod create_project;
+pub mod create_studio_product;
+pub mod delete_device;
+pub mod delete_project;
+pub mod delete_studio_product;
+pub mod describe_device;
+pub mod describe_device_data;
+pub mod describe_device_data_history;
+pub mod describe_model_definition;
+pub mod describe_project;
+pub mod describe_studio_product;
+pub mod get_device_list;
+pub mod get_project_list;
+pub mod get_studio_product_list;
+pub mod models;
+pub mod modify_model_definition;
+pub mod modify_project;
+pub mod modify_studio_product;
+
+pub use call_device_action_async::*;
+pub use call_device_action_sync::*;
+pub use control_device_data::*;
+pub use create_device::*;
+pub use create_project::*;
+pub use create_studio_product::*;
+pub use delete_device::*;
+pub use delete_project::*;
+pub use delete_studio_product::*;
+pub use describe_device::*;
+pub use describe_device_data::*;
+pub use describe_device_data_history::*;
+pub use describe_model_definition::*;
+pub use describe_project::*;
+pub use describe_studio_product::*;
+pub use get_device_list::*;
+pub use get_project_list::*;
+pub use get_studio_product_list::*;
+pub use models::*;
+pub use modify_model_definition::*;
+pub use modify_project::*;
+pub use modify_studio_product::*;
+
+pub const API_VERSION: &str = "2019-04-23";
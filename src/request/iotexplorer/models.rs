@@ -0,0 +1,288 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProjectEntry {
+    #[serde(rename = "ProjectId")]
+    pub project_id: String,
+    #[serde(rename = "ProjectName")]
+    pub project_name: String,
+    #[serde(rename = "ProjectDesc", default)]
+    pub project_desc: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "UpdateTime", default)]
+    pub update_time: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProjectEntryEx {
+    #[serde(flatten)]
+    pub project: ProjectEntry,
+    #[serde(rename = "ProductCount", default)]
+    pub product_count: u64,
+    #[serde(rename = "NativeAppCount", default)]
+    pub native_app_count: u64,
+    #[serde(rename = "WebAppCount", default)]
+    pub web_app_count: u64,
+    #[serde(rename = "InstanceId", default)]
+    pub instance_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProductEntry {
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    #[serde(rename = "ProductName")]
+    pub product_name: String,
+    #[serde(rename = "CategoryId", default)]
+    pub category_id: u64,
+    #[serde(rename = "EncryptionType", default)]
+    pub encryption_type: String,
+    #[serde(rename = "NetType", default)]
+    pub net_type: String,
+    #[serde(rename = "DataProtocol", default)]
+    pub data_protocol: u64,
+    #[serde(rename = "ProductDesc", default)]
+    pub product_desc: String,
+    /// `dev` or `released`.
+    #[serde(rename = "DevStatus", default)]
+    pub dev_status: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "UpdateTime", default)]
+    pub update_time: i64,
+    #[serde(rename = "Region", default)]
+    pub region: String,
+    #[serde(rename = "ProductType", default)]
+    pub product_type: u64,
+    #[serde(rename = "ProjectId", default)]
+    pub project_id: String,
+    #[serde(rename = "ModuleId", default)]
+    pub module_id: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProductModelDefinition {
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    /// The data template as JSON, see `DataTemplate`.
+    #[serde(rename = "ModelDefine")]
+    pub model_define: String,
+    #[serde(rename = "UpdateTime", default)]
+    pub update_time: i64,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: i64,
+}
+
+/// Credentials of a device returned by `CreateDevice`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeviceData {
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    #[serde(rename = "DeviceCert", default)]
+    pub device_cert: String,
+    #[serde(rename = "DevicePrivateKey", default)]
+    pub device_private_key: String,
+    #[serde(rename = "DevicePsk", default)]
+    pub device_psk: String,
+    #[serde(rename = "DevEUI", default)]
+    pub dev_eui: String,
+    #[serde(rename = "AppKey", default)]
+    pub app_key: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeviceInfo {
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    /// 0 inactive, 1 online, 2 offline, 3 disabled.
+    #[serde(rename = "Status", default)]
+    pub status: u64,
+    #[serde(rename = "DevicePsk", default)]
+    pub device_psk: String,
+    #[serde(rename = "FirstOnlineTime", default)]
+    pub first_online_time: i64,
+    #[serde(rename = "LoginTime", default)]
+    pub login_time: i64,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "Version", default)]
+    pub version: String,
+    #[serde(rename = "DeviceCert", default)]
+    pub device_cert: String,
+    #[serde(rename = "LogLevel", default)]
+    pub log_level: u64,
+    #[serde(rename = "EnableState", default)]
+    pub enable_state: u64,
+}
+
+/// The data template (thing model) of a product: what a device reports,
+/// emits and can be asked to do.
+///
+/// Keys this crate doesn't know are kept in `extra` and written back as is,
+/// so a template read with `DescribeModelDefinition` can be modified and
+/// sent back with `ModifyModelDefinition` without losing anything.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct DataTemplate {
+    #[serde(default)]
+    pub version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Value>,
+    #[serde(default)]
+    pub properties: Vec<TemplateProperty>,
+    #[serde(default)]
+    pub events: Vec<TemplateEvent>,
+    #[serde(default)]
+    pub actions: Vec<TemplateAction>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl DataTemplate {
+    pub fn property(&self, id: &str) -> Option<&TemplateProperty> {
+        self.properties.iter().find(|property| property.id == id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TemplateProperty {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub desc: String,
+    /// `r` or `rw`.
+    #[serde(default)]
+    pub mode: String,
+    pub define: DataDefine,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TemplateEvent {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub desc: String,
+    /// `info`, `alert` or `fault`.
+    #[serde(rename = "type", default)]
+    pub event_type: String,
+    #[serde(default)]
+    pub params: Vec<TemplateParam>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TemplateAction {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub desc: String,
+    #[serde(default)]
+    pub input: Vec<TemplateParam>,
+    #[serde(default)]
+    pub output: Vec<TemplateParam>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A parameter of an event or action.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TemplateParam {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(alias = "dataType")]
+    pub define: DataDefine,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A member of a struct, whose type the template stores as `dataType`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StructMember {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "dataType", alias = "define")]
+    pub define: DataDefine,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The type of a value. Numbers are kept as strings, the way the template
+/// stores them.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(remote = "Self", tag = "type", rename_all = "lowercase")]
+pub enum DataDefine {
+    Bool {
+        #[serde(default)]
+        mapping: HashMap<String, String>,
+    },
+    Int(NumberDefine),
+    Float(NumberDefine),
+    Enum {
+        #[serde(default)]
+        mapping: HashMap<String, String>,
+    },
+    String {
+        #[serde(default)]
+        min: String,
+        #[serde(default)]
+        max: String,
+    },
+    Timestamp,
+    Struct {
+        #[serde(default)]
+        specs: Vec<StructMember>,
+    },
+    Array {
+        #[serde(rename = "arrayInfo")]
+        array_info: Box<DataDefine>,
+    },
+    /// A type this crate doesn't know, e.g. `geopoint`, kept as it was read.
+    #[serde(skip)]
+    Unknown(Value),
+}
+
+impl Serialize for DataDefine {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            DataDefine::Unknown(define) => define.serialize(serializer),
+            define => DataDefine::serialize(define, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DataDefine {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let define = Value::deserialize(deserializer)?;
+        Ok(DataDefine::deserialize(define.clone()).unwrap_or(DataDefine::Unknown(define)))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct NumberDefine {
+    #[serde(default)]
+    pub min: String,
+    #[serde(default)]
+    pub max: String,
+    #[serde(default)]
+    pub start: String,
+    #[serde(default)]
+    pub step: String,
+    #[serde(default)]
+    pub unit: String,
+}
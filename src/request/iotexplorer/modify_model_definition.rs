@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::{DataTemplate, API_VERSION};
+
+request! {
+    ModifyModelDefinitionRequest, ModifyModelDefinitionRequestBuilder => ("iotexplorer", "ModifyModelDefinition", API_VERSION) {
+        product_id: String => "ProductId",
+        /// The data template as JSON.
+        model_schema: String => "ModelSchema",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ModifyModelDefinitionResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
+
+impl ModifyModelDefinitionRequestBuilder {
+    pub fn set_data_template(self, template: &DataTemplate) -> serde_json::Result<Self> {
+        Ok(self.set_model_schema(serde_json::to_string(template)?))
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::{ProjectEntry, API_VERSION};
+
+request! {
+    ModifyProjectRequest, ModifyProjectRequestBuilder => ("iotexplorer", "ModifyProject", API_VERSION) {
+        project_id: String => "ProjectId",
+        project_name: String => "ProjectName",
+        project_description: String => "ProjectDescription",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ModifyProjectResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Project")]
+    pub project: ProjectEntry,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{ProductEntry, API_VERSION};
+
+request! {
+    ModifyStudioProductRequest, ModifyStudioProductRequestBuilder => ("iotexplorer", "ModifyStudioProduct", API_VERSION) {
+        product_id: String => "ProductId",
+        product_name: String => "ProductName",
+        product_desc: String => "ProductDesc",
+        module_id: u64 => "ModuleId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ModifyStudioProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Product")]
+    pub product: ProductEntry,
+}
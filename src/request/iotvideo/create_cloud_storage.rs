@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Buys a cloud storage package for a device.
+    CreateCloudStorageRequest, CreateCloudStorageRequestBuilder => ("iotvideo", "CreateCloudStorage", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// e.g. `yc1m3d` for a month of continuous recording kept 3 days.
+        package_id: String => "PackageId",
+        /// 1 to replace the current package, 0 to queue after it.
+        override_current: u64 => "Override",
+        /// `front` or `end`.
+        package_queue: String => "PackageQueue",
+        order_id: String => "OrderId",
+        /// Camera channel of an NVR, 0 otherwise.
+        channel_id: u64 => "ChannelId",
+        storage_region: String => "StorageRegion",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateCloudStorageResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// Price in cents, when billed per order.
+    #[serde(rename = "Price", default)]
+    pub price: u64,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::{DeviceCredential, API_VERSION};
+
+request! {
+    CreateDeviceRequest, CreateDeviceRequestBuilder => ("iotvideo", "CreateDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        defined_psk: String => "DefinedPsk",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data")]
+    pub data: DeviceCredential,
+}
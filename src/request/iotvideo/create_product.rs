@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+use super::{VideoProduct, API_VERSION};
+
+request! {
+    CreateProductRequest, CreateProductRequestBuilder => ("iotvideo", "CreateProduct", API_VERSION) {
+        product_name: String => "ProductName",
+        /// 1 camera, 2 doorbell, 3 NVR...
+        device_type: u64 => "DeviceType",
+        /// Validity of the device licenses, in years.
+        product_vaild_years: u64 => "ProductVaildYears",
+        /// e.g. `Video`, `Audio`, `Storage`.
+        features: Vec<String> => "Features",
+        chip_os: String => "ChipOs",
+        chip_manufacture_id: String => "ChipManufactureId",
+        chip_id: String => "ChipId",
+        product_description: String => "ProductDescription",
+        /// 1 certificate, 2 key.
+        encryption_type: u64 => "EncryptionType",
+        net_type: String => "NetType",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data")]
+    pub data: VideoProduct,
+}
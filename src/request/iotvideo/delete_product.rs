@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteProductRequest, DeleteProductRequestBuilder => ("iotvideo", "DeleteProduct", API_VERSION) {
+        product_id: String => "ProductId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Cloud storage package of a device.
+    DescribeCloudStorageRequest, DescribeCloudStorageRequestBuilder => ("iotvideo", "DescribeCloudStorage", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// Of the app user, for devices bound to several users.
+        user_id: String => "UserId",
+        /// Camera channel of an NVR, 0 otherwise.
+        channel_id: u64 => "ChannelId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeCloudStorageResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// 0 never bought, 1 active, 2 expired.
+    #[serde(rename = "Status")]
+    pub status: u64,
+    /// 1 continuous recording, 2 event recording.
+    #[serde(rename = "Type", default)]
+    pub storage_type: u64,
+    #[serde(rename = "ExpireTime", default)]
+    pub expire_time: i64,
+    /// Days recordings are kept.
+    #[serde(rename = "ShiftDuration", default)]
+    pub shift_duration: u64,
+    #[serde(rename = "UserId", default)]
+    pub user_id: String,
+}
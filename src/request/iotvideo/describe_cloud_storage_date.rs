@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DescribeCloudStorageDateRequest, DescribeCloudStorageDateRequestBuilder => ("iotvideo", "DescribeCloudStorageDate", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// Of the app user, for devices bound to several users.
+        user_id: String => "UserId",
+        /// Camera channel of an NVR, 0 otherwise.
+        channel_id: u64 => "ChannelId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeCloudStorageDateResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    /// Days with recordings, as `YYYY-MM-DD`.
+    #[serde(rename = "Data", default)]
+    pub data: Vec<String>,
+}
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+
+use super::{CloudStorageEvent, API_VERSION};
+
+request! {
+    /// Events recorded by a device. Pass the `Context` of a response to get the
+    /// next page.
+    DescribeCloudStorageEventsRequest, DescribeCloudStorageEventsRequestBuilder => ("iotvideo", "DescribeCloudStorageEvents", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        start_time: i64 => "StartTime",
+        end_time: i64 => "EndTime",
+        context: String => "Context",
+        size: u64 => "Size",
+        event_id: String => "EventId",
+        /// Of the app user, for devices bound to several users.
+        user_id: String => "UserId",
+        /// Camera channel of an NVR, 0 otherwise.
+        channel_id: u64 => "ChannelId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeCloudStorageEventsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Events", default)]
+    pub events: Vec<CloudStorageEvent>,
+    #[serde(rename = "Context", default)]
+    pub context: String,
+    #[serde(rename = "Listover")]
+    pub list_over: bool,
+    #[serde(rename = "Total", default)]
+    pub total: u64,
+    #[serde(rename = "VideoURL", default)]
+    pub video_url: String,
+}
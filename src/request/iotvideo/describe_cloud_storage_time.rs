@@ -0,0 +1,27 @@
+use serde::Deserialize;
+
+use super::{CloudStorageTimeData, API_VERSION};
+
+request! {
+    /// Recorded time ranges of a day, with the url of the recording.
+    DescribeCloudStorageTimeRequest, DescribeCloudStorageTimeRequestBuilder => ("iotvideo", "DescribeCloudStorageTime", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+        /// `YYYY-MM-DD`.
+        date: String => "Date",
+        start_time: i64 => "StartTime",
+        end_time: i64 => "EndTime",
+        /// Of the app user, for devices bound to several users.
+        user_id: String => "UserId",
+        /// Camera channel of an NVR, 0 otherwise.
+        channel_id: u64 => "ChannelId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeCloudStorageTimeResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data")]
+    pub data: CloudStorageTimeData,
+}
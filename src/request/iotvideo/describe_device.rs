@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::{VideoDevice, API_VERSION};
+
+request! {
+    DescribeDeviceRequest, DescribeDeviceRequestBuilder => ("iotvideo", "DescribeDevice", API_VERSION) {
+        product_id: String => "ProductId",
+        device_name: String => "DeviceName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDeviceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data")]
+    pub data: VideoDevice,
+}
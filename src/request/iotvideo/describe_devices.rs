@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::{VideoDevice, API_VERSION};
+
+request! {
+    DescribeDevicesRequest, DescribeDevicesRequestBuilder => ("iotvideo", "DescribeDevices", API_VERSION) {
+        product_id: String => "ProductId",
+        limit: u64 => "Limit",
+        offset: u64 => "Offset",
+        device_name: String => "DeviceName",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeDevicesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Devices", default)]
+    pub devices: Vec<VideoDevice>,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+}
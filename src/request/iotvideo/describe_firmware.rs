@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DescribeFirmwareRequest, DescribeFirmwareRequestBuilder => ("iotvideo", "DescribeFirmware", API_VERSION) {
+        product_id: String => "ProductID",
+        firmware_version: String => "FirmwareVersion",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeFirmwareResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Version")]
+    pub version: String,
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    #[serde(rename = "Name", default)]
+    pub name: String,
+    #[serde(rename = "Description", default)]
+    pub description: String,
+    #[serde(rename = "Md5sum", default)]
+    pub md5sum: String,
+    #[serde(rename = "Createtime", default)]
+    pub create_time: i64,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::{VideoProduct, API_VERSION};
+
+request! {
+    DescribeProductRequest, DescribeProductRequestBuilder => ("iotvideo", "DescribeProduct", API_VERSION) {
+        product_id: String => "ProductId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeProductResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data")]
+    pub data: VideoProduct,
+}
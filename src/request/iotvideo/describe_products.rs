@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::{ProductList, API_VERSION};
+
+request! {
+    DescribeProductsRequest, DescribeProductsRequestBuilder => ("iotvideo", "DescribeProducts", API_VERSION) {
+        limit: u64 => "Limit",
+        offset: u64 => "Offset",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeProductsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Data")]
+    pub data: ProductList,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Signs a cloud storage video url so it can be played without credentials.
+    GenerateSignedVideoURLRequest, GenerateSignedVideoURLRequestBuilder => ("iotvideo", "GenerateSignedVideoURL", API_VERSION) {
+        /// Url returned by e.g. `DescribeCloudStorageTime`.
+        video_url: String => "VideoURL",
+        /// Unix time, in seconds, after which the url stops working.
+        expire_time: i64 => "ExpireTime",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GenerateSignedVideoURLResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "SignedVideoURL")]
+    pub signed_video_url: String,
+}
@@ -0,0 +1,41 @@
+pub mod create_cloud_storage;
+pub mod create_device;
+pub mod create_product;
+pub mod delete_device;
+pub mod delete_firmware;
+pub mod delete_product;
+pub mod describe_cloud_storage;
+pub mod describe_cloud_storage_date;
+pub mod describe_cloud_storage_events;
+pub mod describe_cloud_storage_time;
+pub mod describe_device;
+pub mod describe_devices;
+pub mod describe_firmware;
+pub mod describe_product;
+pub mod describe_products;
+pub mod generate_signed_video_url;
+pub mod models;
+pub mod publish_firmware_update_message;
+pub mod upload_firmware;
+
+pub use create_cloud_storage::*;
+pub use create_device::*;
+pub use create_product::*;
+pub use delete_device::*;
+pub use delete_firmware::*;
+pub use delete_product::*;
+pub use describe_cloud_storage::*;
+pub use describe_cloud_storage_date::*;
+pub use describe_cloud_storage_events::*;
+pub use describe_cloud_storage_time::*;
+pub use describe_device::*;
+pub use describe_devices::*;
+pub use describe_firmware::*;
+pub use describe_product::*;
+pub use describe_products::*;
+pub use generate_signed_video_url::*;
+pub use models::*;
+pub use publish_firmware_update_message::*;
+pub use upload_firmware::*;
+
+pub const API_VERSION: &str = "2021-11-25";
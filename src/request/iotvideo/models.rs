@@ -0,0 +1,95 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct VideoProduct {
+    #[serde(rename = "ProductId")]
+    pub product_id: String,
+    #[serde(rename = "ProductName")]
+    pub product_name: String,
+    #[serde(rename = "DeviceType", default)]
+    pub device_type: u64,
+    #[serde(rename = "EncryptionType", default)]
+    pub encryption_type: u64,
+    #[serde(rename = "Features", default)]
+    pub features: Vec<String>,
+    #[serde(rename = "ChipOs", default)]
+    pub chip_os: String,
+    #[serde(rename = "ChipManufactureId", default)]
+    pub chip_manufacture_id: String,
+    #[serde(rename = "ChipId", default)]
+    pub chip_id: String,
+    #[serde(rename = "ProductDescription", default)]
+    pub product_description: String,
+    #[serde(rename = "CreateTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "UpdateTime", default)]
+    pub update_time: i64,
+    #[serde(rename = "NetType", default)]
+    pub net_type: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProductList {
+    #[serde(rename = "List", default)]
+    pub list: Vec<VideoProduct>,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DeviceCredential {
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    #[serde(rename = "DevicePsk", default)]
+    pub device_psk: String,
+    #[serde(rename = "DeviceCert", default)]
+    pub device_cert: String,
+    #[serde(rename = "DevicePrivateKey", default)]
+    pub device_private_key: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct VideoDevice {
+    #[serde(rename = "DeviceName")]
+    pub device_name: String,
+    /// 0 offline, 1 online.
+    #[serde(rename = "Online", default)]
+    pub online: u64,
+    #[serde(rename = "LoginTime", default)]
+    pub login_time: i64,
+    #[serde(rename = "DevicePsk", default)]
+    pub device_psk: String,
+    #[serde(rename = "EnableState", default)]
+    pub enable_state: u64,
+    #[serde(rename = "ExpireTime", default)]
+    pub expire_time: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CloudStorageTimeData {
+    #[serde(rename = "TimeList", default)]
+    pub time_list: Vec<CloudStorageTimeInfo>,
+    /// Pass it to `GenerateSignedVideoURL` before playing it.
+    #[serde(rename = "VideoURL", default)]
+    pub video_url: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct CloudStorageTimeInfo {
+    #[serde(rename = "StartTime")]
+    pub start_time: i64,
+    #[serde(rename = "EndTime")]
+    pub end_time: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CloudStorageEvent {
+    #[serde(rename = "StartTime")]
+    pub start_time: i64,
+    #[serde(rename = "EndTime")]
+    pub end_time: i64,
+    #[serde(rename = "Thumbnail", default)]
+    pub thumbnail: String,
+    #[serde(rename = "EventId", default)]
+    pub event_id: String,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Tells a device to upgrade to a registered firmware version.
+    PublishFirmwareUpdateMessageRequest, PublishFirmwareUpdateMessageRequestBuilder => ("iotvideo", "PublishFirmwareUpdateMessage", API_VERSION) {
+        product_id: String => "ProductID",
+        device_name: String => "DeviceName",
+        firmware_version: String => "FirmwareVersion",
+        fw_type: String => "FwType",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PublishFirmwareUpdateMessageResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -1,12 +1,20 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
 
 use crate::client::Configuration;
 
 pub use builder::RequestBuilder;
 pub use iotcloud::*;
 pub mod builder;
+pub mod clb;
+pub mod cvm;
 pub mod iotcloud;
+pub mod iotexplorer;
+pub mod iotvideo;
 pub mod scheme;
+pub mod vpc;
 
 pub const ROOT_DOMAIN: &str = "tencentcloudapi.com";
 pub const API_VERSION: &str = "2018-06-14";
@@ -14,6 +22,12 @@ pub const API_VERSION: &str = "2018-06-14";
 pub trait ServiceRequest {
     fn service(&self) -> &'static str;
     fn action(&self) -> &'static str;
+
+    /// Checks the request before it is signed, so an invalid one fails
+    /// without reaching the network.
+    fn validate(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
 
 pub trait IntoRequest {
@@ -22,24 +36,290 @@ pub trait IntoRequest {
     fn into_request(self, config: Configuration) -> RequestBuilder<Self::Request>;
 }
 
+/// Flattens a request into Tencent Cloud's dotted parameter form, e.g.
+/// `Filters.0.Values.1` or `Placement.Zone`.
 pub trait Flat {
     fn flat(&self) -> HashMap<String, String>;
 
-    fn insert<T: ToString>(hm: &mut HashMap<String, String>, key: &str, value: &Option<T>) {
-        if let Some(value) = value {
-            hm.insert(key.to_string(), value.to_string());
-        }
+    fn insert<T: FlatField>(hm: &mut HashMap<String, String>, key: &str, value: &Option<T>) {
+        value.flat_into(hm, key);
     }
 
-    fn insert_slice<T: ToString + Display, V: AsRef<[T]>>(
-        mut hm: &mut HashMap<String, String>,
+    fn insert_slice<T: FlatField, V: AsRef<[T]>>(
+        hm: &mut HashMap<String, String>,
         key: &str,
         values: &Option<V>,
     ) {
         if let Some(values) = values {
-            for (index, value) in values.as_ref().iter().enumerate() {
-                Self::insert(&mut hm, &format!("{}.{}", key, index), &Some(value));
+            values.as_ref().flat_into(hm, key);
+        }
+    }
+}
+
+/// A request field that knows how to write itself into the flat parameter map.
+///
+/// Scalars are written under `key`, booleans as `true`/`false`. Nested
+/// structs write each field under `key.Name`, sequences each element under
+/// `key.N` and maps each entry under `key.<map key>`, so the result matches
+/// the JSON body of the same request.
+pub trait FlatField {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str);
+}
+
+macro_rules! flat_field_to_string {
+    ($($ty:ty),*) => {
+        $(
+            impl FlatField for $ty {
+                fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+                    hm.insert(key.to_string(), self.to_string());
+                }
             }
+        )*
+    };
+}
+
+flat_field_to_string!(str, String, bool, i8, i16, i32, i64, u8, u16, u32, u64, usize, f32, f64);
+
+impl<T: FlatField + ?Sized> FlatField for &T {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        (**self).flat_into(hm, key);
+    }
+}
+
+impl<T: FlatField + ?Sized> FlatField for Box<T> {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        (**self).flat_into(hm, key);
+    }
+}
+
+impl<T: FlatField> FlatField for Option<T> {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        if let Some(value) = self {
+            value.flat_into(hm, key);
+        }
+    }
+}
+
+impl<T: FlatField> FlatField for [T] {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        for (index, value) in self.iter().enumerate() {
+            value.flat_into(hm, &format!("{}.{}", key, index));
         }
     }
 }
+
+impl<T: FlatField> FlatField for Vec<T> {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        self.as_slice().flat_into(hm, key);
+    }
+}
+
+impl<K: Display, V: FlatField, S> FlatField for HashMap<K, V, S> {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        for (name, value) in self {
+            value.flat_into(hm, &format!("{}.{}", key, name));
+        }
+    }
+}
+
+impl<K: Display, V: FlatField> FlatField for BTreeMap<K, V> {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        for (name, value) in self {
+            value.flat_into(hm, &format!("{}.{}", key, name));
+        }
+    }
+}
+
+/// `null` is left out, like an unset field.
+impl FlatField for serde_json::Value {
+    fn flat_into(&self, hm: &mut HashMap<String, String>, key: &str) {
+        use serde_json::Value;
+
+        match self {
+            Value::Null => {}
+            Value::Bool(value) => value.flat_into(hm, key),
+            Value::Number(value) => value.to_string().flat_into(hm, key),
+            Value::String(value) => value.flat_into(hm, key),
+            Value::Array(values) => values.flat_into(hm, key),
+            Value::Object(values) => {
+                for (name, value) in values {
+                    value.flat_into(hm, &format!("{}.{}", key, name));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, HashMap};
+
+    use super::{cvm, iotcloud::*, Flat, FlatField};
+
+    /// Flattens the JSON body of `req` the same way `Flat` should.
+    fn flat_json<T: serde::Serialize>(req: &T) -> HashMap<String, String> {
+        let mut hm = HashMap::new();
+        if let serde_json::Value::Object(fields) = serde_json::to_value(req).unwrap() {
+            for (name, value) in &fields {
+                value.flat_into(&mut hm, name);
+            }
+        }
+        hm
+    }
+
+    fn assert_round_trip<T: Flat + serde::Serialize>(req: &T) -> HashMap<String, String> {
+        let hm = req.flat();
+        assert_eq!(hm, flat_json(req));
+        hm
+    }
+
+    #[test]
+    fn test_flat_round_trip_cvm() {
+        let req = cvm::DescribeInstancesRequest {
+            instance_ids: Some(vec!["ins-1".to_string(), "ins-2".to_string()]),
+            filters: Some(vec![
+                cvm::InstanceFilter::Zone("ap-guangzhou-3".to_string()).into(),
+                cvm::Filter::new("instance-state", vec!["RUNNING", "STOPPED"]),
+            ]),
+            limit: Some(20),
+            ..Default::default()
+        };
+        let hm = assert_round_trip(&req);
+        assert_eq!(hm["InstanceIds.1"], "ins-2");
+        assert_eq!(hm["Filters.1.Values.1"], "STOPPED");
+        assert_eq!(hm["Limit"], "20");
+
+        let req = cvm::RunInstancesRequest {
+            placement: Some(cvm::Placement {
+                zone: "ap-guangzhou-3".to_string(),
+                project_id: Some(0),
+            }),
+            system_disk: Some(cvm::SystemDisk {
+                disk_type: Some("CLOUD_PREMIUM".to_string()),
+                disk_size: Some(50),
+                ..Default::default()
+            }),
+            data_disks: Some(vec![
+                cvm::DataDisk {
+                    disk_size: 100,
+                    delete_with_instance: Some(true),
+                    ..Default::default()
+                },
+                cvm::DataDisk {
+                    disk_size: 200,
+                    delete_with_instance: Some(false),
+                    ..Default::default()
+                },
+            ]),
+            internet_accessible: Some(cvm::InternetAccessible {
+                internet_max_bandwidth_out: Some(10),
+                public_ip_assigned: Some(true),
+                ..Default::default()
+            }),
+            login_settings: Some(cvm::LoginSettings {
+                key_ids: vec!["skey-1".to_string()],
+                ..Default::default()
+            }),
+            tag_specification: Some(vec![cvm::TagSpecification {
+                resource_type: "instance".to_string(),
+                tags: vec![cvm::Tag {
+                    key: "env".to_string(),
+                    value: "test".to_string(),
+                }],
+            }]),
+            dry_run: Some(true),
+            ..Default::default()
+        };
+        let hm = assert_round_trip(&req);
+        assert_eq!(hm["Placement.ProjectId"], "0");
+        assert_eq!(hm["DataDisks.1.DiskSize"], "200");
+        assert_eq!(hm["DataDisks.1.DeleteWithInstance"], "false");
+        assert_eq!(hm["TagSpecification.0.Tags.0.Value"], "test");
+        assert_eq!(hm["DryRun"], "true");
+    }
+
+    #[test]
+    fn test_flat_round_trip_iotcloud() {
+        let req = CreateDeviceRequest {
+            product_id: Some("ABCDEFGHIJ".to_string()),
+            device_name: Some("lamp-1".to_string()),
+            attribute: Some(Attribute {
+                tags: vec![DeviceTag {
+                    tag: "color".to_string(),
+                    tag_type: 2,
+                    value: "red".to_string(),
+                    name: None,
+                }],
+            }),
+            ..Default::default()
+        };
+        let hm = assert_round_trip(&req);
+        assert_eq!(hm["Attribute.Tags.0.Type"], "2");
+
+        let req = CreateProductRequest {
+            product_name: Some("lamp".to_string()),
+            product_properties: Some(ProductPropertiesInput {
+                encryption_type: Some(EncryptionType::Key),
+                format: Some(ProductFormat::Json),
+                register_type: Some(RegisterType::AutoCreate),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let hm = assert_round_trip(&req);
+        assert_eq!(hm["ProductProperties.EncryptionType"], "2");
+        assert_eq!(hm["ProductProperties.Format"], "json");
+        assert_eq!(hm["ProductProperties.RegisterType"], "1");
+
+        let req = CreateTopicRuleRequest {
+            rule_name: Some("forward".to_string()),
+            topic_rule_payload: Some(TopicRulePayload {
+                sql: "SELECT * FROM 'ABCDEFGHIJ/+/event'".to_string(),
+                actions: vec![TopicRuleAction::Republish(RepublishAction {
+                    topic: "ABCDEFGHIJ/lamp-1/control".to_string(),
+                })],
+                rule_disabled: Some(false),
+                ..Default::default()
+            }),
+        };
+        let hm = assert_round_trip(&req);
+        assert!(hm["TopicRulePayload.Actions"].starts_with('['));
+        assert_eq!(hm["TopicRulePayload.RuleDisabled"], "false");
+
+        let req = BatchUpdateFirmwareRequest {
+            product_id: Some("ABCDEFGHIJ".to_string()),
+            upgrade_method: Some(UpgradeMethod::Silent),
+            device_names: Some(vec!["lamp-1".to_string(), "lamp-2".to_string()]),
+            timeout_interval: Some(std::time::Duration::from_secs(600)),
+            ..Default::default()
+        };
+        let hm = assert_round_trip(&req);
+        assert_eq!(hm["UpgradeMethod"], "0");
+        assert_eq!(hm["DeviceNames.1"], "lamp-2");
+        assert_eq!(hm["TimeoutInterval"], "600");
+    }
+
+    #[test]
+    fn test_flat_map() {
+        let mut labels = BTreeMap::new();
+        labels.insert(
+            "env",
+            vec![cvm::Tag {
+                key: "team".to_string(),
+                value: "iot".to_string(),
+            }],
+        );
+        labels.insert("empty", vec![]);
+        let mut hm = HashMap::new();
+        labels.flat_into(&mut hm, "Labels");
+        assert_eq!(hm.len(), 2);
+        assert_eq!(hm["Labels.env.0.Key"], "team");
+        assert_eq!(hm["Labels.env.0.Value"], "iot");
+
+        let mut expected = HashMap::new();
+        serde_json::to_value(&labels)
+            .unwrap()
+            .flat_into(&mut expected, "Labels");
+        assert_eq!(hm, expected);
+    }
+}
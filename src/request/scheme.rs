@@ -21,28 +21,24 @@ impl AsRef<str> for Scheme {
     }
 }
 
+serde_str!(Scheme);
+
 impl FromStr for Scheme {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_ref() {
             "http" => Ok(Scheme::HTTP),
             "https" => Ok(Scheme::HTTPS),
-            // TODO: handle error
-            _ => Err(()),
+            _ => Err(format!("unknown scheme: {}", s)),
         }
     }
 }
 
 impl TryFrom<&str> for Scheme {
-    type Error = ();
+    type Error = String;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
-        match s.to_lowercase().as_ref() {
-            "http" => Ok(Scheme::HTTP),
-            "https" => Ok(Scheme::HTTPS),
-            // TODO: handle error
-            _ => Err(()),
-        }
+        s.parse()
     }
 }
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+use super::{Tag, API_VERSION};
+
+request! {
+    /// Allocates elastic IPs. They are `CREATING` until the returned task is done.
+    AllocateAddressesRequest, AllocateAddressesRequestBuilder => ("vpc", "AllocateAddresses", API_VERSION) {
+        address_count: i64 => "AddressCount",
+        /// e.g. `TRAFFIC_POSTPAID_BY_HOUR` or `BANDWIDTH_POSTPAID_BY_HOUR`.
+        internet_charge_type: String => "InternetChargeType",
+        /// In Mbps.
+        internet_max_bandwidth_out: i64 => "InternetMaxBandwidthOut",
+        /// `EIP` by default, or `AnycastEIP`.
+        address_type: String => "AddressType",
+        address_name: String => "AddressName",
+        tags: Vec<Tag> => "Tags",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AllocateAddressesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "AddressSet", default)]
+    pub address_set: Vec<String>,
+    #[serde(rename = "TaskId")]
+    pub task_id: String,
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Binds an elastic IP to an instance, or to a private IP of a network
+    /// interface.
+    AssociateAddressRequest, AssociateAddressRequestBuilder => ("vpc", "AssociateAddress", API_VERSION) {
+        address_id: String => "AddressId",
+        instance_id: String => "InstanceId",
+        network_interface_id: String => "NetworkInterfaceId",
+        /// Required with `NetworkInterfaceId`.
+        private_ip_address: String => "PrivateIpAddress",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AssociateAddressResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: String,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    AttachNetworkInterfaceRequest, AttachNetworkInterfaceRequestBuilder => ("vpc", "AttachNetworkInterface", API_VERSION) {
+        network_interface_id: String => "NetworkInterfaceId",
+        instance_id: String => "InstanceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AttachNetworkInterfaceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+use super::{NatGateway, Tag, API_VERSION};
+
+request! {
+    CreateNatGatewayRequest, CreateNatGatewayRequestBuilder => ("vpc", "CreateNatGateway", API_VERSION) {
+        nat_gateway_name: String => "NatGatewayName",
+        vpc_id: String => "VpcId",
+        /// In Mbps.
+        internet_max_bandwidth_out: u64 => "InternetMaxBandwidthOut",
+        max_concurrent_connection: u64 => "MaxConcurrentConnection",
+        /// Elastic IPs to allocate for the gateway.
+        address_count: u64 => "AddressCount",
+        /// Existing elastic IPs to bind to the gateway.
+        public_ip_addresses: Vec<String> => "PublicIpAddresses",
+        zone: String => "Zone",
+        subnet_id: String => "SubnetId",
+        tags: Vec<Tag> => "Tags",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateNatGatewayResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "NatGatewaySet", default)]
+    pub nat_gateway_set: Vec<NatGateway>,
+}
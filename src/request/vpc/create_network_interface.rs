@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use super::{NetworkInterface, PrivateIpAddressSpecification, Tag, API_VERSION};
+
+request! {
+    CreateNetworkInterfaceRequest, CreateNetworkInterfaceRequestBuilder => ("vpc", "CreateNetworkInterface", API_VERSION) {
+        vpc_id: String => "VpcId",
+        subnet_id: String => "SubnetId",
+        network_interface_name: String => "NetworkInterfaceName",
+        network_interface_description: String => "NetworkInterfaceDescription",
+        /// Private IPs to assign automatically.
+        secondary_private_ip_address_count: u64 => "SecondaryPrivateIpAddressCount",
+        private_ip_addresses: Vec<PrivateIpAddressSpecification> => "PrivateIpAddresses",
+        security_group_ids: Vec<String> => "SecurityGroupIds",
+        tags: Vec<Tag> => "Tags",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateNetworkInterfaceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "NetworkInterface")]
+    pub network_interface: NetworkInterface,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::{RouteTable, Tag, API_VERSION};
+
+request! {
+    CreateRouteTableRequest, CreateRouteTableRequestBuilder => ("vpc", "CreateRouteTable", API_VERSION) {
+        vpc_id: String => "VpcId",
+        route_table_name: String => "RouteTableName",
+        tags: Vec<Tag> => "Tags",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateRouteTableResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "RouteTable")]
+    pub route_table: RouteTable,
+}
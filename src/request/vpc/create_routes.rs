@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{Route, RouteTable, API_VERSION};
+
+request! {
+    CreateRoutesRequest, CreateRoutesRequestBuilder => ("vpc", "CreateRoutes", API_VERSION) {
+        route_table_id: String => "RouteTableId",
+        routes: Vec<Route> => "Routes",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateRoutesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "RouteTableSet", default)]
+    pub route_table_set: Vec<RouteTable>,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{SecurityGroup, Tag, API_VERSION};
+
+request! {
+    CreateSecurityGroupRequest, CreateSecurityGroupRequestBuilder => ("vpc", "CreateSecurityGroup", API_VERSION) {
+        group_name: String => "GroupName",
+        group_description: String => "GroupDescription",
+        project_id: String => "ProjectId",
+        tags: Vec<Tag> => "Tags",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateSecurityGroupResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "SecurityGroup")]
+    pub security_group: SecurityGroup,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::{SecurityGroupPolicySet, API_VERSION};
+
+request! {
+    /// Adds policies to a security group. Only one direction may be set per
+    /// call; a `PolicyIndex` inserts the policies at that position.
+    CreateSecurityGroupPoliciesRequest, CreateSecurityGroupPoliciesRequestBuilder => ("vpc", "CreateSecurityGroupPolicies", API_VERSION) {
+        security_group_id: String => "SecurityGroupId",
+        security_group_policy_set: SecurityGroupPolicySet => "SecurityGroupPolicySet",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateSecurityGroupPoliciesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::{Subnet, Tag, API_VERSION};
+
+request! {
+    CreateSubnetRequest, CreateSubnetRequestBuilder => ("vpc", "CreateSubnet", API_VERSION) {
+        vpc_id: String => "VpcId",
+        subnet_name: String => "SubnetName",
+        /// Must be inside the CIDR block of the VPC.
+        cidr_block: String => "CidrBlock",
+        zone: String => "Zone",
+        tags: Vec<Tag> => "Tags",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateSubnetResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Subnet")]
+    pub subnet: Subnet,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use super::{Tag, Vpc, API_VERSION};
+
+request! {
+    CreateVpcRequest, CreateVpcRequestBuilder => ("vpc", "CreateVpc", API_VERSION) {
+        vpc_name: String => "VpcName",
+        /// e.g. `10.0.0.0/16`; inside `10.0.0.0/12`, `172.16.0.0/12` or
+        /// `192.168.0.0/16`.
+        cidr_block: String => "CidrBlock",
+        /// `true` or `false`.
+        enable_multicast: String => "EnableMulticast",
+        dns_servers: Vec<String> => "DnsServers",
+        domain_name: String => "DomainName",
+        tags: Vec<Tag> => "Tags",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateVpcResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "Vpc")]
+    pub vpc: Vpc,
+}
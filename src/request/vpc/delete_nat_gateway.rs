@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteNatGatewayRequest, DeleteNatGatewayRequestBuilder => ("vpc", "DeleteNatGateway", API_VERSION) {
+        nat_gateway_id: String => "NatGatewayId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteNatGatewayResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteNetworkInterfaceRequest, DeleteNetworkInterfaceRequestBuilder => ("vpc", "DeleteNetworkInterface", API_VERSION) {
+        network_interface_id: String => "NetworkInterfaceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteNetworkInterfaceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteRouteTableRequest, DeleteRouteTableRequestBuilder => ("vpc", "DeleteRouteTable", API_VERSION) {
+        route_table_id: String => "RouteTableId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteRouteTableResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
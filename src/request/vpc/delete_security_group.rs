@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteSecurityGroupRequest, DeleteSecurityGroupRequestBuilder => ("vpc", "DeleteSecurityGroup", API_VERSION) {
+        security_group_id: String => "SecurityGroupId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteSecurityGroupResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DeleteSubnetRequest, DeleteSubnetRequestBuilder => ("vpc", "DeleteSubnet", API_VERSION) {
+        subnet_id: String => "SubnetId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteSubnetResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    /// Deletes a VPC. Its subnets, route tables and gateways must be deleted first.
+    DeleteVpcRequest, DeleteVpcRequestBuilder => ("vpc", "DeleteVpc", API_VERSION) {
+        vpc_id: String => "VpcId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteVpcResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use super::{Address, Filter, API_VERSION};
+
+request! {
+    DescribeAddressesRequest, DescribeAddressesRequestBuilder => ("vpc", "DescribeAddresses", API_VERSION) {
+        address_ids: Vec<String> => "AddressIds",
+        /// e.g. `address-ip`, `address-status` or `instance-id`.
+        /// Cannot be combined with `AddressIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: i64 => "Offset",
+        /// At most 100.
+        limit: i64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeAddressesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "AddressSet", default)]
+    pub address_set: Vec<Address>,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use super::{Filter, NatGateway, API_VERSION};
+
+request! {
+    DescribeNatGatewaysRequest, DescribeNatGatewaysRequestBuilder => ("vpc", "DescribeNatGateways", API_VERSION) {
+        nat_gateway_ids: Vec<String> => "NatGatewayIds",
+        /// e.g. `vpc-id` or `nat-gateway-name`. Cannot be combined with `NatGatewayIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: u64 => "Offset",
+        /// At most 100.
+        limit: u64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeNatGatewaysResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "NatGatewaySet", default)]
+    pub nat_gateway_set: Vec<NatGateway>,
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use super::{Filter, NetworkInterface, API_VERSION};
+
+request! {
+    DescribeNetworkInterfacesRequest, DescribeNetworkInterfacesRequestBuilder => ("vpc", "DescribeNetworkInterfaces", API_VERSION) {
+        network_interface_ids: Vec<String> => "NetworkInterfaceIds",
+        /// e.g. `vpc-id`, `subnet-id` or `attachment.instance-id`.
+        /// Cannot be combined with `NetworkInterfaceIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: u64 => "Offset",
+        /// At most 100.
+        limit: u64 => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeNetworkInterfacesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "NetworkInterfaceSet", default)]
+    pub network_interface_set: Vec<NetworkInterface>,
+}
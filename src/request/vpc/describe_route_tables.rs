@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use super::{Filter, RouteTable, API_VERSION};
+
+request! {
+    DescribeRouteTablesRequest, DescribeRouteTablesRequestBuilder => ("vpc", "DescribeRouteTables", API_VERSION) {
+        route_table_ids: Vec<String> => "RouteTableIds",
+        /// e.g. `vpc-id`, `route-table-name` or `association.main`.
+        /// Cannot be combined with `RouteTableIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: String => "Offset",
+        /// At most 100.
+        limit: String => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeRouteTablesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "RouteTableSet", default)]
+    pub route_table_set: Vec<RouteTable>,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::{SecurityGroupPolicySet, API_VERSION};
+
+request! {
+    DescribeSecurityGroupPoliciesRequest, DescribeSecurityGroupPoliciesRequestBuilder => ("vpc", "DescribeSecurityGroupPolicies", API_VERSION) {
+        security_group_id: String => "SecurityGroupId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeSecurityGroupPoliciesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "SecurityGroupPolicySet")]
+    pub security_group_policy_set: SecurityGroupPolicySet,
+}
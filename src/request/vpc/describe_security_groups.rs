@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use super::{Filter, SecurityGroup, API_VERSION};
+
+request! {
+    DescribeSecurityGroupsRequest, DescribeSecurityGroupsRequestBuilder => ("vpc", "DescribeSecurityGroups", API_VERSION) {
+        security_group_ids: Vec<String> => "SecurityGroupIds",
+        /// e.g. `security-group-name`, `project-id` or `tag:<key>`.
+        /// Cannot be combined with `SecurityGroupIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: String => "Offset",
+        /// At most 100.
+        limit: String => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeSecurityGroupsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "SecurityGroupSet", default)]
+    pub security_group_set: Vec<SecurityGroup>,
+}
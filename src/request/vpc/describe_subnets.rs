@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use super::{Filter, Subnet, API_VERSION};
+
+request! {
+    DescribeSubnetsRequest, DescribeSubnetsRequestBuilder => ("vpc", "DescribeSubnets", API_VERSION) {
+        subnet_ids: Vec<String> => "SubnetIds",
+        /// e.g. `vpc-id`, `zone` or `subnet-name`. Cannot be combined with `SubnetIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: String => "Offset",
+        /// At most 100.
+        limit: String => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeSubnetsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "SubnetSet", default)]
+    pub subnet_set: Vec<Subnet>,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use super::{Filter, Vpc, API_VERSION};
+
+request! {
+    DescribeVpcsRequest, DescribeVpcsRequestBuilder => ("vpc", "DescribeVpcs", API_VERSION) {
+        vpc_ids: Vec<String> => "VpcIds",
+        /// e.g. `vpc-name`, `is-default` or `tag:<key>`. Cannot be combined with `VpcIds`.
+        filters: Vec<Filter> => "Filters",
+        offset: String => "Offset",
+        /// At most 100.
+        limit: String => "Limit",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DescribeVpcsResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TotalCount")]
+    pub total_count: u64,
+    #[serde(rename = "VpcSet", default)]
+    pub vpc_set: Vec<Vpc>,
+}
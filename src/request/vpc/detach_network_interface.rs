@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DetachNetworkInterfaceRequest, DetachNetworkInterfaceRequestBuilder => ("vpc", "DetachNetworkInterface", API_VERSION) {
+        network_interface_id: String => "NetworkInterfaceId",
+        instance_id: String => "InstanceId",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DetachNetworkInterfaceResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
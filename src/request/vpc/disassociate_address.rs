@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    DisassociateAddressRequest, DisassociateAddressRequestBuilder => ("vpc", "DisassociateAddress", API_VERSION) {
+        address_id: String => "AddressId",
+        /// Gives the instance a plain public IP instead.
+        reallocate_normal_public_ip: bool => "ReallocateNormalPublicIp",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DisassociateAddressResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: String,
+}
@@ -0,0 +1,63 @@
+pub mod allocate_addresses;
+pub mod associate_address;
+pub mod attach_network_interface;
+pub mod create_nat_gateway;
+pub mod create_network_interface;
+pub mod create_route_table;
+pub mod create_routes;
+pub mod create_security_group;
+pub mod create_security_group_policies;
+pub mod create_subnet;
+pub mod create_vpc;
+pub mod delete_nat_gateway;
+pub mod delete_network_interface;
+pub mod delete_route_table;
+pub mod delete_security_group;
+pub mod delete_subnet;
+pub mod delete_vpc;
+pub mod describe_addresses;
+pub mod describe_nat_gateways;
+pub mod describe_network_interfaces;
+pub mod describe_route_tables;
+pub mod describe_security_group_policies;
+pub mod describe_security_groups;
+pub mod describe_subnets;
+pub mod describe_vpcs;
+pub mod detach_network_interface;
+pub mod disassociate_address;
+pub mod models;
+pub mod release_addresses;
+pub mod replace_security_group_policy;
+
+pub use allocate_addresses::*;
+pub use associate_address::*;
+pub use attach_network_interface::*;
+pub use create_nat_gateway::*;
+pub use create_network_interface::*;
+pub use create_route_table::*;
+pub use create_routes::*;
+pub use create_security_group::*;
+pub use create_security_group_policies::*;
+pub use create_subnet::*;
+pub use create_vpc::*;
+pub use delete_nat_gateway::*;
+pub use delete_network_interface::*;
+pub use delete_route_table::*;
+pub use delete_security_group::*;
+pub use delete_subnet::*;
+pub use delete_vpc::*;
+pub use describe_addresses::*;
+pub use describe_nat_gateways::*;
+pub use describe_network_interfaces::*;
+pub use describe_route_tables::*;
+pub use describe_security_group_policies::*;
+pub use describe_security_groups::*;
+pub use describe_subnets::*;
+pub use describe_vpcs::*;
+pub use detach_network_interface::*;
+pub use disassociate_address::*;
+pub use models::*;
+pub use release_addresses::*;
+pub use replace_security_group_policy::*;
+
+pub const API_VERSION: &str = "2017-03-12";
@@ -0,0 +1,492 @@
+use serde::{Deserialize, Serialize};
+use std::{convert::Infallible, str::FromStr};
+
+/// VPC shares the filter and tag types of CVM.
+pub use crate::request::cvm::{Filter, Tag};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Vpc {
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+    #[serde(rename = "VpcName", default)]
+    pub vpc_name: String,
+    #[serde(rename = "CidrBlock", default)]
+    pub cidr_block: String,
+    #[serde(rename = "IsDefault", default)]
+    pub is_default: bool,
+    #[serde(rename = "EnableMulticast", default)]
+    pub enable_multicast: bool,
+    #[serde(rename = "DnsServerSet", default)]
+    pub dns_server_set: Vec<String>,
+    #[serde(rename = "DomainName", default)]
+    pub domain_name: String,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: Vec<Tag>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Subnet {
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+    #[serde(rename = "VpcId", default)]
+    pub vpc_id: String,
+    #[serde(rename = "SubnetName", default)]
+    pub subnet_name: String,
+    #[serde(rename = "CidrBlock", default)]
+    pub cidr_block: String,
+    #[serde(rename = "IsDefault", default)]
+    pub is_default: bool,
+    #[serde(rename = "Zone", default)]
+    pub zone: String,
+    #[serde(rename = "RouteTableId", default)]
+    pub route_table_id: String,
+    #[serde(rename = "AvailableIpAddressCount", default)]
+    pub available_ip_address_count: u64,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: Vec<Tag>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SecurityGroup {
+    #[serde(rename = "SecurityGroupId")]
+    pub security_group_id: String,
+    #[serde(rename = "SecurityGroupName", default)]
+    pub security_group_name: String,
+    #[serde(rename = "SecurityGroupDesc", default)]
+    pub security_group_desc: String,
+    #[serde(rename = "ProjectId", default)]
+    pub project_id: String,
+    #[serde(rename = "IsDefault", default)]
+    pub is_default: bool,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: Vec<Tag>,
+}
+
+/// What a security group policy does with matching traffic.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyAction {
+    Accept,
+    Drop,
+    Unknown(String),
+}
+
+impl AsRef<str> for PolicyAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            PolicyAction::Accept => "ACCEPT",
+            PolicyAction::Drop => "DROP",
+            PolicyAction::Unknown(s) => s,
+        }
+    }
+}
+
+impl FromStr for PolicyAction {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCEPT" => PolicyAction::Accept,
+            "DROP" => PolicyAction::Drop,
+            s => PolicyAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+serde_str!(PolicyAction);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+    Icmp,
+    Icmpv6,
+    All,
+    Unknown(String),
+}
+
+impl AsRef<str> for Protocol {
+    fn as_ref(&self) -> &str {
+        match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+            Protocol::Icmp => "ICMP",
+            Protocol::Icmpv6 => "ICMPv6",
+            Protocol::All => "ALL",
+            Protocol::Unknown(s) => s,
+        }
+    }
+}
+
+impl FromStr for Protocol {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "TCP" | "tcp" => Protocol::Tcp,
+            "UDP" | "udp" => Protocol::Udp,
+            "ICMP" | "icmp" => Protocol::Icmp,
+            "ICMPv6" | "icmpv6" => Protocol::Icmpv6,
+            "ALL" | "all" => Protocol::All,
+            s => Protocol::Unknown(s.to_string()),
+        })
+    }
+}
+
+serde_str!(Protocol);
+
+/// A single rule of a security group. The source (or destination, for
+/// egress) is one of `CidrBlock`, `Ipv6CidrBlock` or `SecurityGroupId`.
+///
+/// ```ignore
+/// SecurityGroupPolicy::new(PolicyAction::Accept)
+///     .protocol(Protocol::Tcp)
+///     .port("22,443")
+///     .cidr_block("10.0.0.0/8")
+///     .description("ssh and https from the office");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SecurityGroupPolicy {
+    /// Position of the policy in its direction, starting from 0.
+    #[serde(
+        rename = "PolicyIndex",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub policy_index: Option<i64>,
+    #[serde(rename = "Protocol", default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<Protocol>,
+    /// e.g. `80`, `80,443`, `3306-20000` or `ALL`.
+    #[serde(rename = "Port", default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    #[serde(rename = "CidrBlock", default, skip_serializing_if = "Option::is_none")]
+    pub cidr_block: Option<String>,
+    #[serde(
+        rename = "Ipv6CidrBlock",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ipv6_cidr_block: Option<String>,
+    #[serde(
+        rename = "SecurityGroupId",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub security_group_id: Option<String>,
+    #[serde(rename = "Action", default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<PolicyAction>,
+    #[serde(
+        rename = "PolicyDescription",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub policy_description: Option<String>,
+    /// Only set in responses.
+    #[serde(rename = "ModifyTime", default, skip_serializing)]
+    pub modify_time: Option<String>,
+}
+
+impl SecurityGroupPolicy {
+    pub fn new(action: PolicyAction) -> SecurityGroupPolicy {
+        SecurityGroupPolicy {
+            action: Some(action),
+            ..Default::default()
+        }
+    }
+
+    pub fn index(mut self, index: i64) -> Self {
+        self.policy_index = Some(index);
+        self
+    }
+
+    pub fn protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    pub fn port<S: Into<String>>(mut self, port: S) -> Self {
+        self.port = Some(port.into());
+        self
+    }
+
+    pub fn cidr_block<S: Into<String>>(mut self, cidr_block: S) -> Self {
+        self.cidr_block = Some(cidr_block.into());
+        self
+    }
+
+    pub fn ipv6_cidr_block<S: Into<String>>(mut self, ipv6_cidr_block: S) -> Self {
+        self.ipv6_cidr_block = Some(ipv6_cidr_block.into());
+        self
+    }
+
+    /// Matches traffic from (or to) the instances of another security group.
+    pub fn security_group<S: Into<String>>(mut self, security_group_id: S) -> Self {
+        self.security_group_id = Some(security_group_id.into());
+        self
+    }
+
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.policy_description = Some(description.into());
+        self
+    }
+}
+
+flat_field!(SecurityGroupPolicy {
+    policy_index => "PolicyIndex",
+    protocol => "Protocol",
+    port => "Port",
+    cidr_block => "CidrBlock",
+    ipv6_cidr_block => "Ipv6CidrBlock",
+    security_group_id => "SecurityGroupId",
+    action => "Action",
+    policy_description => "PolicyDescription",
+});
+
+/// The ingress and egress policies of a security group.
+///
+/// ```ignore
+/// SecurityGroupPolicySet::new()
+///     .ingress(SecurityGroupPolicy::new(PolicyAction::Accept).protocol(Protocol::Tcp).port("443"))
+///     .egress(SecurityGroupPolicy::new(PolicyAction::Accept).protocol(Protocol::All));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SecurityGroupPolicySet {
+    /// Version of the policies; updates are rejected when it is stale.
+    #[serde(rename = "Version", default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(rename = "Egress", default, skip_serializing_if = "Vec::is_empty")]
+    pub egress: Vec<SecurityGroupPolicy>,
+    #[serde(rename = "Ingress", default, skip_serializing_if = "Vec::is_empty")]
+    pub ingress: Vec<SecurityGroupPolicy>,
+}
+
+impl SecurityGroupPolicySet {
+    pub fn new() -> SecurityGroupPolicySet {
+        SecurityGroupPolicySet::default()
+    }
+
+    pub fn version<S: Into<String>>(mut self, version: S) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn ingress(mut self, policy: SecurityGroupPolicy) -> Self {
+        self.ingress.push(policy);
+        self
+    }
+
+    pub fn egress(mut self, policy: SecurityGroupPolicy) -> Self {
+        self.egress.push(policy);
+        self
+    }
+}
+
+flat_field!(SecurityGroupPolicySet {
+    version => "Version",
+    egress => "Egress",
+    ingress => "Ingress",
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Route {
+    #[serde(rename = "DestinationCidrBlock")]
+    pub destination_cidr_block: String,
+    /// e.g. `CVM`, `VPN`, `NAT`, `PEERCONNECTION` or `EIP`.
+    #[serde(rename = "GatewayType")]
+    pub gateway_type: String,
+    #[serde(rename = "GatewayId")]
+    pub gateway_id: String,
+    /// Only set in responses.
+    #[serde(rename = "RouteId", default, skip_serializing)]
+    pub route_id: Option<u64>,
+    #[serde(
+        rename = "RouteDescription",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub route_description: Option<String>,
+    /// Only set in responses.
+    #[serde(rename = "Enabled", default, skip_serializing)]
+    pub enabled: Option<bool>,
+}
+
+flat_field!(Route {
+    destination_cidr_block => "DestinationCidrBlock",
+    gateway_type => "GatewayType",
+    gateway_id => "GatewayId",
+    route_description => "RouteDescription",
+});
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RouteTableAssociation {
+    #[serde(rename = "SubnetId")]
+    pub subnet_id: String,
+    #[serde(rename = "RouteTableId")]
+    pub route_table_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RouteTable {
+    #[serde(rename = "RouteTableId")]
+    pub route_table_id: String,
+    #[serde(rename = "RouteTableName", default)]
+    pub route_table_name: String,
+    #[serde(rename = "VpcId", default)]
+    pub vpc_id: String,
+    /// Subnets using this route table.
+    #[serde(rename = "AssociationSet", default)]
+    pub association_set: Vec<RouteTableAssociation>,
+    #[serde(rename = "RouteSet", default)]
+    pub route_set: Vec<Route>,
+    /// Whether this is the default route table of the VPC.
+    #[serde(rename = "Main", default)]
+    pub main: bool,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: Vec<Tag>,
+}
+
+/// An elastic IP.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Address {
+    #[serde(rename = "AddressId")]
+    pub address_id: String,
+    #[serde(rename = "AddressName", default)]
+    pub address_name: Option<String>,
+    /// e.g. `CREATING`, `BINDING`, `BIND`, `UNBINDING` or `UNBIND`.
+    #[serde(rename = "AddressStatus", default)]
+    pub address_status: String,
+    #[serde(rename = "AddressIp", default)]
+    pub address_ip: String,
+    #[serde(rename = "AddressType", default)]
+    pub address_type: String,
+    #[serde(rename = "InstanceId", default)]
+    pub instance_id: Option<String>,
+    #[serde(rename = "NetworkInterfaceId", default)]
+    pub network_interface_id: Option<String>,
+    #[serde(rename = "PrivateAddressIp", default)]
+    pub private_address_ip: Option<String>,
+    #[serde(rename = "IsArrears", default)]
+    pub is_arrears: bool,
+    #[serde(rename = "IsBlocked", default)]
+    pub is_blocked: bool,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NatGatewayAddress {
+    #[serde(rename = "AddressId")]
+    pub address_id: String,
+    #[serde(rename = "PublicIpAddress", default)]
+    pub public_ip_address: String,
+    #[serde(rename = "IsBlocked", default)]
+    pub is_blocked: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NatGateway {
+    #[serde(rename = "NatGatewayId")]
+    pub nat_gateway_id: String,
+    #[serde(rename = "NatGatewayName", default)]
+    pub nat_gateway_name: String,
+    /// e.g. `PENDING`, `AVAILABLE` or `FAILED`.
+    #[serde(rename = "State", default)]
+    pub state: String,
+    #[serde(rename = "VpcId", default)]
+    pub vpc_id: String,
+    #[serde(rename = "Zone", default)]
+    pub zone: String,
+    #[serde(rename = "SubnetId", default)]
+    pub subnet_id: Option<String>,
+    /// In Mbps.
+    #[serde(rename = "InternetMaxBandwidthOut", default)]
+    pub internet_max_bandwidth_out: u64,
+    #[serde(rename = "MaxConcurrentConnection", default)]
+    pub max_concurrent_connection: u64,
+    #[serde(rename = "PublicIpAddressSet", default)]
+    pub public_ip_address_set: Vec<NatGatewayAddress>,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: Vec<Tag>,
+}
+
+/// A private IP of a network interface.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct PrivateIpAddressSpecification {
+    #[serde(rename = "PrivateIpAddress")]
+    pub private_ip_address: String,
+    #[serde(rename = "Primary", default, skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+    #[serde(
+        rename = "Description",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    /// Only set in responses.
+    #[serde(rename = "PublicIpAddress", default, skip_serializing)]
+    pub public_ip_address: Option<String>,
+    /// Only set in responses.
+    #[serde(rename = "AddressId", default, skip_serializing)]
+    pub address_id: Option<String>,
+}
+
+flat_field!(PrivateIpAddressSpecification {
+    private_ip_address => "PrivateIpAddress",
+    primary => "Primary",
+    description => "Description",
+});
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NetworkInterfaceAttachment {
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+    #[serde(rename = "DeviceIndex", default)]
+    pub device_index: u64,
+    #[serde(rename = "AttachTime", default)]
+    pub attach_time: String,
+}
+
+/// An elastic network interface.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NetworkInterface {
+    #[serde(rename = "NetworkInterfaceId")]
+    pub network_interface_id: String,
+    #[serde(rename = "NetworkInterfaceName", default)]
+    pub network_interface_name: String,
+    #[serde(rename = "NetworkInterfaceDescription", default)]
+    pub network_interface_description: String,
+    #[serde(rename = "VpcId", default)]
+    pub vpc_id: String,
+    #[serde(rename = "SubnetId", default)]
+    pub subnet_id: String,
+    /// Ids of the security groups bound to the interface.
+    #[serde(rename = "GroupSet", default)]
+    pub group_set: Vec<String>,
+    /// Whether this is the primary interface of an instance.
+    #[serde(rename = "Primary", default)]
+    pub primary: bool,
+    #[serde(rename = "MacAddress", default)]
+    pub mac_address: String,
+    /// e.g. `PENDING`, `AVAILABLE`, `ATTACHING`, `DETACHING` or `DELETING`.
+    #[serde(rename = "State", default)]
+    pub state: String,
+    #[serde(rename = "PrivateIpAddressSet", default)]
+    pub private_ip_address_set: Vec<PrivateIpAddressSpecification>,
+    #[serde(rename = "Attachment", default)]
+    pub attachment: Option<NetworkInterfaceAttachment>,
+    #[serde(rename = "Zone", default)]
+    pub zone: String,
+    #[serde(rename = "CreatedTime", default)]
+    pub created_time: String,
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: Vec<Tag>,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use super::API_VERSION;
+
+request! {
+    ReleaseAddressesRequest, ReleaseAddressesRequestBuilder => ("vpc", "ReleaseAddresses", API_VERSION) {
+        address_ids: Vec<String> => "AddressIds",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReleaseAddressesResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+    #[serde(rename = "TaskId")]
+    pub task_id: String,
+}
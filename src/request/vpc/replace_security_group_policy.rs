@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use super::{SecurityGroupPolicySet, API_VERSION};
+
+request! {
+    /// Replaces the single policy at the `PolicyIndex` of the one direction set
+    /// in `SecurityGroupPolicySet`.
+    ReplaceSecurityGroupPolicyRequest, ReplaceSecurityGroupPolicyRequestBuilder => ("vpc", "ReplaceSecurityGroupPolicy", API_VERSION) {
+        security_group_id: String => "SecurityGroupId",
+        security_group_policy_set: SecurityGroupPolicySet => "SecurityGroupPolicySet",
+        /// The policy being replaced, checked before replacing it.
+        original_security_group_policy_set: SecurityGroupPolicySet => "OriginalSecurityGroupPolicySet",
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReplaceSecurityGroupPolicyResponse {
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
@@ -4,4 +4,49 @@ use serde::Deserialize;
 pub struct Response<T> {
     #[serde(rename = "Response")]
     response: T,
+    #[serde(skip)]
+    request_id: Option<String>,
+}
+
+impl<T> Response<T> {
+    pub(crate) fn new(response: T, request_id: Option<String>) -> Self {
+        Response {
+            response,
+            request_id,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.response
+    }
+
+    /// The `RequestId` Tencent Cloud assigned to the call.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+}
+
+impl<T> std::ops::Deref for Response<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
+    }
+}
+
+/// The body Tencent Cloud returns in place of `T` when an action fails.
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    #[serde(rename = "Error")]
+    pub error: ApiError,
+    #[serde(rename = "RequestId")]
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    #[serde(rename = "Code")]
+    pub code: String,
+    #[serde(rename = "Message")]
+    pub message: String,
 }
@@ -0,0 +1,109 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+
+use super::{HttpRequest, HttpResponse, HttpTransport};
+
+/// An in-process transport that answers with canned responses.
+///
+/// Responses are registered per `X-TC-Action`, or per HTTP method (e.g.
+/// `PUT`) for requests without one such as COS ones. When several responses are
+/// queued for one action they are returned in order, and the last one keeps
+/// being returned once the queue is drained, which is handy for polling.
+/// Every request that passes through is recorded for later assertions.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: HashMap<String, VecDeque<HttpResponse>>,
+    fallback: Option<HttpResponse>,
+    requests: Vec<HttpRequest>,
+}
+
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// Queues a `200 OK` response with the given JSON body for `action`.
+    pub fn respond<S: Into<String>>(&self, action: &str, body: S) -> &Self {
+        self.respond_with(action, HttpResponse::new(200, body.into()))
+    }
+
+    pub fn respond_with(&self, action: &str, response: HttpResponse) -> &Self {
+        let mut state = self.state.lock().unwrap();
+        state
+            .responses
+            .entry(action.to_string())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// The response returned for actions without a registered response.
+    pub fn fallback(&self, response: HttpResponse) -> &Self {
+        self.state.lock().unwrap().fallback = Some(response);
+        self
+    }
+
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    pub fn requests_for(&self, action: &str) -> Vec<HttpRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|req| action_of(req) == Some(action))
+            .collect()
+    }
+}
+
+/// A client of the `ap-guangzhou` region sending through `transport`.
+#[cfg(test)]
+pub(crate) fn client(transport: &MockTransport) -> crate::client::Client {
+    crate::client::Client::builder()
+        .transport(transport.clone())
+        .region(crate::Region::APGuangzhou)
+        .build()
+        .unwrap()
+}
+
+/// The body of the last request sent for `action`.
+#[cfg(test)]
+pub(crate) fn body(transport: &MockTransport, action: &str) -> String {
+    let requests = transport.requests_for(action);
+    String::from_utf8(requests.last().unwrap().body.clone().unwrap()).unwrap()
+}
+
+fn action_of(req: &HttpRequest) -> Option<&str> {
+    match req.headers.get("X-TC-Action") {
+        Some(action) => action.to_str().ok(),
+        None => Some(req.method.as_str()),
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MockTransport {
+    async fn send(&self, request: HttpRequest) -> crate::Result<HttpResponse> {
+        let mut state = self.state.lock().unwrap();
+        let action = action_of(&request).unwrap_or_default().to_string();
+        state.requests.push(request);
+        let response = match state.responses.get_mut(&action) {
+            Some(queue) if queue.len() > 1 => queue.pop_front(),
+            Some(queue) => queue.front().cloned(),
+            None => None,
+        };
+        match response.or_else(|| state.fallback.clone()) {
+            Some(response) => Ok(response),
+            None => Err(crate::Error::Transport(
+                format!("mock transport has no response for action {:?}", action).into(),
+            )),
+        }
+    }
+}
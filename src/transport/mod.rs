@@ -0,0 +1,96 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use http::{HeaderMap, Method};
+
+pub mod mock;
+#[cfg(feature = "reqwest")]
+pub mod reqwest;
+
+#[cfg(feature = "reqwest")]
+pub use self::reqwest::ReqwestTransport;
+pub use mock::MockTransport;
+
+/// A fully signed request, ready to be put on the wire.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn new<B: Into<Vec<u8>>>(status: u16, body: B) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers: Default::default(),
+            body: body.into(),
+        }
+    }
+}
+
+/// A response whose body is read as it arrives, e.g. a large COS object.
+pub struct StreamingResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: BoxStream<'static, crate::Result<Bytes>>,
+}
+
+impl StreamingResponse {
+    /// Reads the rest of the body into memory.
+    pub async fn collect(self) -> crate::Result<HttpResponse> {
+        let chunks: Vec<Bytes> = self.body.try_collect().await?;
+        Ok(HttpResponse {
+            status: self.status,
+            headers: self.headers,
+            body: chunks.concat(),
+        })
+    }
+}
+
+impl Debug for StreamingResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
+
+impl From<HttpResponse> for StreamingResponse {
+    fn from(response: HttpResponse) -> Self {
+        let body = Bytes::from(response.body);
+        StreamingResponse {
+            status: response.status,
+            headers: response.headers,
+            body: futures::stream::once(async move { Ok(body) }).boxed(),
+        }
+    }
+}
+
+/// The seam between the request pipeline and the network.
+///
+/// `Client` never talks to an HTTP library directly; every signed request is
+/// handed to a transport. reqwest is the default implementation (behind the
+/// `reqwest` feature), `MockTransport` serves canned responses in-process.
+#[async_trait]
+pub trait HttpTransport: Send + Sync + Debug {
+    async fn send(&self, request: HttpRequest) -> crate::Result<HttpResponse>;
+
+    /// Like `send`, but hands the body over as it arrives. Transports that
+    /// buffer the body anyway can keep the default, which yields it in one
+    /// chunk.
+    async fn send_streaming(&self, request: HttpRequest) -> crate::Result<StreamingResponse> {
+        Ok(self.send(request).await?.into())
+    }
+}
@@ -0,0 +1,186 @@
+use std::{convert::TryFrom, time::Duration};
+
+use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{HTTProfile, ProxyConfig, TlsBackend, TlsIdentity};
+
+use super::{HttpRequest, HttpResponse, HttpTransport, StreamingResponse};
+
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(http_profile: &HTTProfile) -> crate::Result<ReqwestTransport> {
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(http_profile.timeout))
+            .pool_idle_timeout(Duration::from_secs(3600))
+            .pool_max_idle_per_host(100);
+        builder = tls_backend(builder, http_profile.tls_backend)?;
+        if let Some(ref proxy) = http_profile.proxy {
+            builder = builder.proxy(proxy_of(proxy)?);
+        }
+        for pem in &http_profile.root_certificates {
+            for certificate in reqwest::Certificate::from_pem_bundle(pem)? {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        if let Some(ref identity) = http_profile.identity {
+            builder = with_identity(builder, identity, http_profile.tls_backend)?;
+        }
+        let client = builder.build()?;
+        Ok(ReqwestTransport { client })
+    }
+
+    pub fn from_client(client: reqwest::Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+fn proxy_of(config: &ProxyConfig) -> crate::Result<reqwest::Proxy> {
+    let mut proxy = reqwest::Proxy::all(&config.url)?;
+    if let Some(ref username) = config.username {
+        proxy = proxy.basic_auth(username, config.password.as_deref().unwrap_or_default());
+    }
+    let no_proxy = match config.no_proxy {
+        Some(ref no_proxy) => reqwest::NoProxy::from_string(no_proxy),
+        None => reqwest::NoProxy::from_env(),
+    };
+    Ok(proxy.no_proxy(no_proxy))
+}
+
+fn tls_backend(
+    builder: reqwest::ClientBuilder,
+    backend: TlsBackend,
+) -> crate::Result<reqwest::ClientBuilder> {
+    match backend {
+        TlsBackend::Default => Ok(builder),
+        #[cfg(feature = "native-tls")]
+        TlsBackend::NativeTls => Ok(builder.use_native_tls()),
+        #[cfg(feature = "rustls-tls")]
+        TlsBackend::Rustls => Ok(builder.use_rustls_tls()),
+        #[allow(unreachable_patterns)]
+        backend => Err(crate::Error::Configuration(format!(
+            "tls backend {:?} is not compiled in, enable its cargo feature",
+            backend
+        ))),
+    }
+}
+
+fn with_identity(
+    builder: reqwest::ClientBuilder,
+    identity: &TlsIdentity,
+    backend: TlsBackend,
+) -> crate::Result<reqwest::ClientBuilder> {
+    #[cfg(feature = "rustls-tls")]
+    if backend == TlsBackend::Rustls {
+        let mut pem = identity.private_key.clone();
+        pem.push(b'\n');
+        pem.extend_from_slice(&identity.certificate);
+        return Ok(builder.identity(reqwest::Identity::from_pem(&pem)?));
+    }
+    #[cfg(feature = "native-tls")]
+    if backend != TlsBackend::Rustls {
+        let identity =
+            reqwest::Identity::from_pkcs8_pem(&identity.certificate, &identity.private_key)?;
+        return Ok(builder.use_native_tls().identity(identity));
+    }
+    let _ = (builder, identity);
+    Err(crate::Error::Configuration(format!(
+        "client identity is not supported by tls backend {:?}, enable `native-tls` or `rustls-tls`",
+        backend
+    )))
+}
+
+impl TryFrom<HttpRequest> for reqwest::Request {
+    type Error = crate::Error;
+
+    fn try_from(req: HttpRequest) -> crate::Result<Self> {
+        let url = reqwest::Url::parse(&req.url)
+            .map_err(|e| crate::Error::InvalidUrl(format!("{}: {}", req.url, e)))?;
+        let mut request = reqwest::Request::new(req.method, url);
+        if let Some(body) = req.body {
+            *request.body_mut() = Some(body.into());
+        }
+        *request.headers_mut() = req.headers;
+        Ok(request)
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> crate::Result<HttpResponse> {
+        let request = reqwest::Request::try_from(request)?;
+        let response = self.client.execute(request).await?;
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    async fn send_streaming(&self, request: HttpRequest) -> crate::Result<StreamingResponse> {
+        let request = reqwest::Request::try_from(request)?;
+        let response = self.client.execute(request).await?;
+        Ok(StreamingResponse {
+            status: response.status().as_u16(),
+            headers: response.headers().clone(),
+            body: response.bytes_stream().map_err(crate::Error::from).boxed(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(not(feature = "rustls-tls"))]
+    use crate::TlsBackend;
+    use crate::{HTTProfile, ProxyConfig};
+
+    use super::ReqwestTransport;
+
+    #[test]
+    fn test_proxy() {
+        let profile = HTTProfile {
+            proxy: Some(
+                ProxyConfig::new("http://proxy.internal:3128")
+                    .basic_auth("user", "pass")
+                    .no_proxy("localhost,.internal"),
+            ),
+            ..Default::default()
+        };
+        assert!(ReqwestTransport::new(&profile).is_ok());
+
+        let profile = HTTProfile {
+            proxy: Some(ProxyConfig::new("not a url")),
+            ..Default::default()
+        };
+        assert!(ReqwestTransport::new(&profile).is_err());
+    }
+
+    #[test]
+    fn test_root_certificates() {
+        let profile = HTTProfile {
+            root_certificates: vec![b"-----BEGIN CERTIFICATE-----\ngarbage\n".to_vec()],
+            ..Default::default()
+        };
+        assert!(ReqwestTransport::new(&profile).is_err());
+    }
+
+    #[cfg(not(feature = "rustls-tls"))]
+    #[test]
+    fn test_tls_backend_not_compiled() {
+        let profile = HTTProfile {
+            tls_backend: TlsBackend::Rustls,
+            ..Default::default()
+        };
+        match ReqwestTransport::new(&profile) {
+            Err(crate::Error::Configuration(_)) => {}
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
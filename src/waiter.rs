@@ -0,0 +1,107 @@
+use std::{fmt::Debug, future::Future, time::Duration};
+
+use futures::future::BoxFuture;
+use tokio::time::Instant;
+
+use crate::{response::Response, Error, ResponseError, ResponseResult};
+
+/// What a waiter predicate makes of one Describe response.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WaitState {
+    Pending,
+    Success,
+    Failure,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum WaitError<R: Debug> {
+    #[error("the operation reached a failure state")]
+    Failure(Response<R>),
+    #[error("the operation did not complete after {0} attempts")]
+    MaxAttempts(usize),
+    #[error("the operation did not complete before the deadline")]
+    Timeout,
+    #[error(transparent)]
+    Request(Error),
+    #[error(transparent)]
+    Response(ResponseError),
+}
+
+type Poll<R> = Box<dyn FnMut() -> BoxFuture<'static, ResponseResult<Response<R>>> + Send>;
+
+/// Calls a Describe action until `predicate` reports a terminal state.
+///
+/// The first call is made immediately, later ones `delay` apart, until
+/// `max_attempts` calls have been made or `deadline` has passed.
+pub struct Waiter<R> {
+    poll: Poll<R>,
+    predicate: Box<dyn Fn(&R) -> WaitState + Send + Sync>,
+    delay: Duration,
+    max_attempts: usize,
+    deadline: Option<Duration>,
+}
+
+impl<R: Debug> Waiter<R> {
+    pub fn new<F, Fut, P>(mut poll: F, predicate: P) -> Waiter<R>
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ResponseResult<Response<R>>> + Send + 'static,
+        P: Fn(&R) -> WaitState + Send + Sync + 'static,
+    {
+        Waiter {
+            poll: Box::new(move || Box::pin(poll())),
+            predicate: Box::new(predicate),
+            delay: Duration::from_secs(5),
+            max_attempts: 60,
+            deadline: None,
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Gives up once `deadline` has elapsed since `wait` was called,
+    /// cancelling the call in flight if any.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub async fn wait(self) -> Result<Response<R>, WaitError<R>> {
+        match self.deadline {
+            Some(deadline) => {
+                let deadline = Instant::now() + deadline;
+                tokio::time::timeout_at(deadline, self.run())
+                    .await
+                    .unwrap_or(Err(WaitError::Timeout))
+            }
+            None => self.run().await,
+        }
+    }
+
+    async fn run(mut self) -> Result<Response<R>, WaitError<R>> {
+        for attempt in 0..self.max_attempts {
+            if attempt > 0 {
+                tokio::time::sleep(self.delay).await;
+            }
+            let response = match (self.poll)().await {
+                Ok(Ok(response)) => response,
+                Ok(Err(e)) => return Err(WaitError::Response(e)),
+                Err(e) => return Err(WaitError::Request(e)),
+            };
+            match (self.predicate)(&response) {
+                WaitState::Pending => continue,
+                WaitState::Success => return Ok(response),
+                WaitState::Failure => return Err(WaitError::Failure(response)),
+            }
+        }
+        Err(WaitError::MaxAttempts(self.max_attempts))
+    }
+}